@@ -0,0 +1,77 @@
+use std::{collections::BTreeMap, fs};
+
+use serde::{Deserialize, Serialize};
+use solution::Answer;
+
+use crate::days::{Day, Part};
+
+/// Expected answers for one input file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    part1: Option<AnswerValue>,
+    part2: Option<AnswerValue>,
+}
+
+/// An `Answer` as written in the TOML files: a number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum AnswerValue {
+    Number(i64),
+    Text(String),
+}
+
+impl AnswerValue {
+    /// `None` for `Answer::Unsolved`, which can't be written down.
+    pub(crate) fn from_answer(answer: &Answer) -> Option<Self> {
+        match answer {
+            Answer::Number(n) => Some(AnswerValue::Number(*n)),
+            Answer::Text(s) => Some(AnswerValue::Text(s.clone())),
+            Answer::Unsolved => None,
+        }
+    }
+}
+
+impl From<AnswerValue> for Answer {
+    fn from(value: AnswerValue) -> Self {
+        match value {
+            AnswerValue::Number(n) => Answer::Number(n),
+            AnswerValue::Text(s) => Answer::Text(s),
+        }
+    }
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<Answer> {
+        let answer = match part {
+            Part::One => self.part1.clone()?,
+            Part::Two => self.part2.clone()?,
+        };
+        Some(answer.into())
+    }
+}
+
+/// Whether `answer` is the `expected` one. Multi-line answers (grids) are
+/// compared without their trailing newlines.
+pub fn matches(expected: &Answer, answer: &Answer) -> bool {
+    match (expected, answer) {
+        (Answer::Text(e), Answer::Text(a)) => e.trim_end_matches('\n') == a.trim_end_matches('\n'),
+        _ => expected == answer,
+    }
+}
+
+/// Reads the day's `inputs/answers.toml`, which maps input file names to
+/// their expected answers:
+///
+/// ```toml
+/// ["example.txt"]
+/// part1 = 5
+/// part2 = 12
+/// ```
+///
+/// Returns `None` when the day has no answers file.
+pub fn load(day: &Day) -> Option<solution::Result<BTreeMap<String, Expected>>> {
+    let path = crate::inputs_dir(day).join("answers.toml");
+    let content = fs::read_to_string(&path).ok()?;
+    Some(toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e).into()))
+}
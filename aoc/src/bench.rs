@@ -0,0 +1,153 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use solution::ParseError;
+
+use crate::days::{Day, Part};
+
+/// Time spent measuring each stage.
+const STAGE_BUDGET: Duration = Duration::from_millis(500);
+const MAX_RUNS: u128 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    pub fn values() -> &'static [Self] {
+        &[Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part(Part::One) => "part1",
+            Stage::Part(Part::Two) => "part2",
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part {}", part.number()),
+        }
+    }
+}
+
+/// Median time of a stage over as many runs as fit in `STAGE_BUDGET`.
+pub fn time_stage(day: &Day, input: &str, stage: Stage) -> Result<Duration, ParseError> {
+    let first = day.time(input, stage, 1)?[0];
+    let runs = (STAGE_BUDGET.as_nanos() / first.as_nanos().max(1)).clamp(1, MAX_RUNS);
+    Ok(median(day.time(input, stage, runs as u32)?))
+}
+
+/// The upper median of non-empty `timings`.
+fn median(mut timings: Vec<Duration>) -> Duration {
+    timings.sort_unstable();
+    timings[timings.len() / 2]
+}
+
+/// Relative change from `baseline` to `current`, in percent. `None` for a
+/// zero baseline, from which any change is infinite.
+pub fn change(baseline: Duration, current: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+    Some((current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+/// Previously measured timings, in nanoseconds, keyed by day name and stage:
+///
+/// ```toml
+/// [day_05]
+/// parse = 142034
+/// part1 = 7066154
+/// part2 = 12718232
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, u64>>);
+
+impl Baseline {
+    /// Timings depend on the machine, so the baseline lives in `target`.
+    pub fn default_path() -> PathBuf {
+        crate::workspace_root()
+            .join("target")
+            .join("aoc-bench")
+            .join("baseline.toml")
+    }
+
+    /// Loads the baseline, which is empty if it was never saved.
+    pub fn load(path: &Path) -> solution::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e).into())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("can't read {}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> solution::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: &Day, stage: Stage) -> Option<Duration> {
+        let nanos = *self.0.get(&day.name())?.get(stage.key())?;
+        Some(Duration::from_nanos(nanos))
+    }
+
+    pub fn set(&mut self, day: &Day, stage: Stage, time: Duration) {
+        self.0
+            .entry(day.name())
+            .or_default()
+            .insert(stage.key().to_owned(), time.as_nanos() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn change_and_median() {
+        let ms = Duration::from_millis;
+        assert_eq!(change(ms(200), ms(400)), Some(100.0));
+        assert_eq!(change(ms(200), ms(100)), Some(-50.0));
+        assert_eq!(change(Duration::ZERO, ms(1)), None);
+        assert_eq!(median(vec![ms(3), ms(1), ms(2)]), ms(2));
+        assert_eq!(median(vec![ms(4), ms(1), ms(3), ms(2)]), ms(3));
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let day = days::get(5).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.set(day, Stage::Parse, Duration::from_nanos(142034));
+        baseline.set(day, Stage::Part(Part::Two), Duration::from_micros(12));
+
+        let toml = toml::to_string(&baseline).unwrap();
+        assert_eq!(toml, "[day_05]\nparse = 142034\npart2 = 12000\n");
+        let baseline: Baseline = toml::from_str(&toml).unwrap();
+        assert_eq!(
+            baseline.get(day, Stage::Parse),
+            Some(Duration::from_nanos(142034))
+        );
+        assert_eq!(baseline.get(day, Stage::Part(Part::One)), None);
+    }
+}
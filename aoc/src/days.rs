@@ -0,0 +1,124 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use solution::{Answer, ParseError, Solution};
+
+use crate::bench::Stage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn values() -> &'static [Self] {
+        &[Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    /// Parses the input and runs the requested part on it.
+    solve: fn(&str, Part) -> Result<Answer, ParseError>,
+    /// Runs a stage on the input `runs` times, returning the time of each run.
+    time: fn(&str, Stage, u32) -> Result<Vec<Duration>, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            solve: |input, part| {
+                let input = S::parse(input)?;
+                Ok(match part {
+                    Part::One => S::part1(&input),
+                    Part::Two => S::part2(&input),
+                })
+            },
+            time: |input, stage, runs| {
+                let parsed = S::parse(input)?;
+                Ok((0..runs)
+                    .map(|_| {
+                        let start = Instant::now();
+                        match stage {
+                            Stage::Parse => drop(black_box(S::parse(black_box(input)))),
+                            Stage::Part(Part::One) => drop(black_box(S::part1(black_box(&parsed)))),
+                            Stage::Part(Part::Two) => drop(black_box(S::part2(black_box(&parsed)))),
+                        }
+                        start.elapsed()
+                    })
+                    .collect())
+            },
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("day_{:02}", self.number)
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<Answer, ParseError> {
+        (self.solve)(input, part)
+    }
+
+    pub fn time(&self, input: &str, stage: Stage, runs: u32) -> Result<Vec<Duration>, ParseError> {
+        (self.time)(input, stage, runs)
+    }
+}
+
+const DAYS: &[Day] = &[
+    Day::new::<day_01::Day01>(),
+    Day::new::<day_02::Day02>(),
+    Day::new::<day_03::Day03>(),
+    Day::new::<day_04::Day04>(),
+    Day::new::<day_05::Day05>(),
+    Day::new::<day_06::Day06>(),
+    Day::new::<day_07::Day07>(),
+    Day::new::<day_08::Day08>(),
+    Day::new::<day_09::Day09>(),
+    Day::new::<day_10::Day10>(),
+    Day::new::<day_11::Day11>(),
+    Day::new::<day_12::Day12>(),
+    Day::new::<day_13::Day13>(),
+    Day::new::<day_14::Day14>(),
+    Day::new::<day_15::Day15>(),
+    Day::new::<day_16::Day16>(),
+    Day::new::<day_18::Day18>(),
+    Day::new::<day_19::Day19>(),
+    Day::new::<day_20::Day20>(),
+    Day::new::<day_21::Day21>(),
+    Day::new::<day_23::Day23>(),
+    Day::new::<day_24::Day24>(),
+    Day::new::<day_25::Day25>(),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+/// Day numbers of the `day_XX` crates listed in the root workspace manifest.
+pub fn workspace_days() -> Vec<u8> {
+    let manifest = include_str!("../../Cargo.toml");
+    let mut days: Vec<u8> = manifest
+        .lines()
+        .filter_map(|l| {
+            l.trim()
+                .trim_end_matches(',')
+                .trim_matches('"')
+                .strip_prefix("day_")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    days.sort_unstable();
+    days
+}
@@ -0,0 +1,89 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings of `aoc fetch`, read from `aoc.toml` at the workspace root:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "http://localhost:8080"
+/// ```
+///
+/// The `AOC_SESSION` and `AOC_BASE_URL` environment variables take
+/// precedence over the file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FetchConfig {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl FetchConfig {
+    pub fn default_path() -> PathBuf {
+        crate::workspace_root().join("aoc.toml")
+    }
+
+    pub fn load(path: &Path) -> solution::Result<Self> {
+        let mut config: Self = match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(format!("can't read {}: {}", path.display(), e).into()),
+        };
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, nothing was downloaded.
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` to `dest`, unless the file already exists.
+pub fn fetch(config: &FetchConfig, day: u8, dest: &Path) -> solution::Result<Fetched> {
+    if dest.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let session = config
+        .session
+        .as_deref()
+        .ok_or("no session cookie, set AOC_SESSION or `session` in aoc.toml")?;
+
+    let url = format!(
+        "{}/2021/day/{}/input",
+        config.base_url().trim_end_matches('/'),
+        day
+    );
+    let input = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .call()?
+        .into_string()?;
+
+    // Write to a temporary file first, so an interrupted download doesn't
+    // leave a partial input behind that would then be considered cached.
+    if let Some(dir) = dest.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = dest.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, dest)?;
+    Ok(Fetched::Downloaded)
+}
@@ -0,0 +1,239 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+use solution::Answer;
+
+use crate::{
+    answers::{self, AnswerValue},
+    days::Part,
+};
+
+/// Answers submitted for the day's puzzle input, kept in `day_XX/ledger.toml`:
+///
+/// ```toml
+/// [part1]
+/// correct = 6841
+///
+/// [[part2.rejected]]
+/// answer = 20000
+/// hint = "high"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ledger {
+    #[serde(default, skip_serializing_if = "PartLedger::is_empty")]
+    part1: PartLedger,
+    #[serde(default, skip_serializing_if = "PartLedger::is_empty")]
+    part2: PartLedger,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartLedger {
+    correct: Option<AnswerValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rejected: Vec<Rejected>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rejected {
+    answer: AnswerValue,
+    hint: Option<Hint>,
+}
+
+/// What the site said about a rejected answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Hint {
+    High,
+    Low,
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(Hint::High),
+            "low" => Ok(Hint::Low),
+            _ => Err(format!("invalid hint {:?}, expected high or low", s)),
+        }
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Hint::High => "too high",
+            Hint::Low => "too low",
+        })
+    }
+}
+
+impl PartLedger {
+    fn is_empty(&self) -> bool {
+        self.correct.is_none() && self.rejected.is_empty()
+    }
+}
+
+impl Ledger {
+    pub fn path(day: u8) -> PathBuf {
+        solution::inputs_dir(day)
+            .parent()
+            .unwrap()
+            .join("ledger.toml")
+    }
+
+    /// Loads the ledger, which is empty if nothing was recorded yet.
+    pub fn load(path: &Path) -> solution::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e).into())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("can't read {}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> solution::Result<()> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn part(&self, part: Part) -> &PartLedger {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut PartLedger {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+
+    pub fn record_correct(&mut self, part: Part, answer: &Answer) {
+        let ledger = self.part_mut(part);
+        ledger.correct = AnswerValue::from_answer(answer);
+        ledger
+            .rejected
+            .retain(|r| Some(&r.answer) != ledger.correct.as_ref());
+    }
+
+    pub fn record_rejected(&mut self, part: Part, answer: &Answer, hint: Option<Hint>) {
+        let Some(answer) = AnswerValue::from_answer(answer) else {
+            return;
+        };
+        let rejected = &mut self.part_mut(part).rejected;
+        rejected.retain(|r| r.answer != answer);
+        rejected.push(Rejected { answer, hint });
+    }
+
+    /// Warnings about `answer` given what was already submitted: a different
+    /// answer was confirmed, the same answer was rejected, or it is out of the
+    /// bounds given by the hints.
+    pub fn check(&self, part: Part, answer: &Answer) -> Vec<String> {
+        let ledger = self.part(part);
+        let mut warnings = Vec::new();
+        if let Some(correct) = &ledger.correct {
+            let correct = Answer::from(correct.clone());
+            if answers::matches(&correct, answer) {
+                return warnings;
+            }
+            warnings.push(format!("differs from the confirmed answer {}", correct));
+        }
+        let mut lowest_high = None;
+        let mut highest_low = None;
+        for rejected in &ledger.rejected {
+            let rejected_answer = Answer::from(rejected.answer.clone());
+            if answers::matches(&rejected_answer, answer) {
+                match rejected.hint {
+                    Some(hint) => warnings.push(format!("was already rejected as {}", hint)),
+                    None => warnings.push("was already rejected".to_owned()),
+                }
+            }
+            match (rejected_answer, rejected.hint) {
+                (Answer::Number(n), Some(Hint::High)) => {
+                    lowest_high = Some(lowest_high.map_or(n, |h: i64| h.min(n)))
+                }
+                (Answer::Number(n), Some(Hint::Low)) => {
+                    highest_low = Some(highest_low.map_or(n, |l: i64| l.max(n)))
+                }
+                _ => {}
+            }
+        }
+        if let Answer::Number(n) = *answer {
+            match (lowest_high, highest_low) {
+                (Some(high), _) if n > high => {
+                    warnings.push(format!("is above {}, which is too high", high))
+                }
+                (_, Some(low)) if n < low => {
+                    warnings.push(format!("is below {}, which is too low", low))
+                }
+                _ => {}
+            }
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(ledger: &Ledger, answer: impl Into<Answer>) -> Vec<String> {
+        ledger.check(Part::One, &answer.into())
+    }
+
+    #[test]
+    fn confirmed_answer() {
+        let mut ledger = Ledger::default();
+        assert!(check(&ledger, 42).is_empty());
+
+        ledger.record_rejected(Part::One, &42.into(), None);
+        ledger.record_correct(Part::One, &42.into());
+        assert!(ledger.part1.rejected.is_empty());
+        assert!(check(&ledger, 42).is_empty());
+        assert_eq!(check(&ledger, 43), ["differs from the confirmed answer 42"]);
+        assert!(ledger.check(Part::Two, &43.into()).is_empty());
+    }
+
+    #[test]
+    fn rejected_answers() {
+        let mut ledger = Ledger::default();
+        ledger.record_rejected(Part::One, &"ABC".into(), None);
+        ledger.record_rejected(Part::One, &7.into(), Some(Hint::High));
+        assert_eq!(check(&ledger, "ABC"), ["was already rejected"]);
+        assert_eq!(check(&ledger, 7), ["was already rejected as too high"]);
+
+        ledger.record_rejected(Part::One, &7.into(), Some(Hint::Low));
+        assert_eq!(ledger.part1.rejected.len(), 2);
+        assert_eq!(check(&ledger, 7), ["was already rejected as too low"]);
+    }
+
+    #[test]
+    fn hint_bounds() {
+        let mut ledger = Ledger::default();
+        for (answer, hint) in [
+            (100, Hint::High),
+            (50, Hint::High),
+            (10, Hint::Low),
+            (20, Hint::Low),
+        ] {
+            ledger.record_rejected(Part::One, &answer.into(), Some(hint));
+        }
+        assert!(check(&ledger, 30).is_empty());
+        assert_eq!(check(&ledger, 60), ["is above 50, which is too high"]);
+        assert_eq!(check(&ledger, 15), ["is below 20, which is too low"]);
+        assert_eq!(check(&ledger, 50), ["was already rejected as too high"]);
+        assert!(check(&ledger, "60").is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod fetch;
+pub mod ledger;
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// The `inputs` directory of a day crate.
+pub fn inputs_dir(day: &days::Day) -> PathBuf {
+    solution::inputs_dir(day.number)
+}
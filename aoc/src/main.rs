@@ -0,0 +1,333 @@
+use std::time::Instant;
+
+use aoc::{
+    bench::{self, Baseline, Stage},
+    days::{self, Day, Part},
+    fetch::{self, FetchConfig, Fetched},
+    ledger::{Hint, Ledger},
+};
+use solution::{Answer, InputSource, OutputFormat, PuzzleInput, Record};
+
+const USAGE: &str = "\
+usage:
+    aoc run --day <N> [--part <1|2>] [--input <PATH|-> | --example <N>] [--json]
+    aoc run --all [--json]
+    aoc bench [--day <N>] [--save-baseline]
+    aoc fetch --day <N>
+    aoc ledger --day <N> [--part <1|2> (--correct <ANSWER> | --rejected <ANSWER> [--hint <high|low>])]";
+
+enum Command {
+    Run {
+        day: u8,
+        part: Option<Part>,
+        input: InputSource,
+        format: OutputFormat,
+    },
+    RunAll {
+        format: OutputFormat,
+    },
+    Bench {
+        day: Option<u8>,
+        save_baseline: bool,
+    },
+    Fetch {
+        day: u8,
+    },
+    Ledger {
+        day: u8,
+        submission: Option<(Part, Submission)>,
+    },
+}
+
+enum Submission {
+    Correct(Answer),
+    Rejected(Answer, Option<Hint>),
+}
+
+/// Answers given on the command line are numbers when they parse as one.
+fn parse_answer(s: &str) -> Answer {
+    match s.parse() {
+        Ok(n) => Answer::Number(n),
+        Err(_) => Answer::Text(s.to_owned()),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = args.next().ok_or("missing command")?;
+
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut all = false;
+    let mut save_baseline = false;
+    let mut format = OutputFormat::Human;
+    let mut submission = None;
+    let mut hint = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--day" => {
+                let v = value()?;
+                day = Some(v.parse().map_err(|_| format!("invalid day {:?}", v))?);
+            }
+            "--part" => {
+                part = Some(match value()?.as_str() {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    v => return Err(format!("invalid part {:?}", v)),
+                })
+            }
+            "--input" if input.is_some() => return Err("only one input can be given".to_owned()),
+            "--input" => input = Some(InputSource::from_arg(&value()?)),
+            "--example" if input.is_some() => return Err("only one input can be given".to_owned()),
+            "--example" => {
+                let v = value()?;
+                let n = v.parse().map_err(|_| format!("invalid example {:?}", v))?;
+                input = Some(InputSource::Example(n));
+            }
+            "--all" => all = true,
+            "--save-baseline" => save_baseline = true,
+            "--json" => format = OutputFormat::Json,
+            "--correct" | "--rejected" if submission.is_some() => {
+                return Err("only one answer can be recorded".to_owned())
+            }
+            "--correct" => submission = Some(Submission::Correct(parse_answer(&value()?))),
+            "--rejected" => submission = Some(Submission::Rejected(parse_answer(&value()?), None)),
+            "--hint" => hint = Some(value()?.parse()?),
+            _ => return Err(format!("unknown argument {:?}", arg)),
+        }
+    }
+
+    match command.as_str() {
+        "run" if save_baseline => Err("--save-baseline only applies to bench".to_owned()),
+        "run" => match (all, day) {
+            (true, None) if part.is_none() && input.is_none() => Ok(Command::RunAll { format }),
+            (true, _) => Err("--all can't be combined with other arguments".to_owned()),
+            (false, Some(day)) => Ok(Command::Run {
+                day,
+                part,
+                input: input.unwrap_or(InputSource::Puzzle),
+                format,
+            }),
+            (false, None) => Err("one of --day or --all is required".to_owned()),
+        },
+        "bench" if all || part.is_some() || input.is_some() || format == OutputFormat::Json => {
+            Err("bench only takes --day and --save-baseline".to_owned())
+        }
+        "bench" => Ok(Command::Bench { day, save_baseline }),
+        "fetch" => match day {
+            Some(day) if !all && !save_baseline && part.is_none() && input.is_none() => {
+                Ok(Command::Fetch { day })
+            }
+            Some(_) => Err("fetch only takes --day".to_owned()),
+            None => Err("--day is required".to_owned()),
+        },
+        "ledger" if all || save_baseline || input.is_some() || format == OutputFormat::Json => {
+            Err("ledger only takes --day, --part, --correct, --rejected and --hint".to_owned())
+        }
+        "ledger" => {
+            let day = day.ok_or("--day is required")?;
+            let submission = match (submission, hint) {
+                (Some(Submission::Rejected(answer, None)), hint) => {
+                    Some(Submission::Rejected(answer, hint))
+                }
+                (_, Some(_)) => return Err("--hint only applies to --rejected".to_owned()),
+                (submission, None) => submission,
+            };
+            let submission = match (part, submission) {
+                (Some(part), Some(submission)) => Some((part, submission)),
+                (None, None) => None,
+                (Some(_), None) => return Err("--part needs --correct or --rejected".to_owned()),
+                (None, Some(_)) => return Err("--part is required to record an answer".to_owned()),
+            };
+            Ok(Command::Ledger { day, submission })
+        }
+        other => Err(format!("unknown command {:?}", other)),
+    }
+}
+
+fn load_input(day: &Day, source: &InputSource) -> Result<PuzzleInput, String> {
+    PuzzleInput::load(day.number, source).map_err(|e| format!("{}: {}", day.name(), e))
+}
+
+fn has_puzzle_input(day: &Day) -> bool {
+    InputSource::Puzzle.resolve(day.number).is_ok()
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let input = load_input(day, source)?;
+    // The ledger is about the answers of the puzzle input only.
+    let ledger = match source {
+        InputSource::Puzzle => {
+            Some(Ledger::load(&Ledger::path(day.number)).map_err(|e| e.to_string())?)
+        }
+        _ => None,
+    };
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = day
+            .solve(&input.text, part)
+            .map_err(|e| format!("{} {}:{}", day.name(), input.name, e))?;
+        let elapsed = start.elapsed();
+        for warning in ledger.iter().flat_map(|l| l.check(part, &answer)) {
+            let shown = match &answer {
+                Answer::Text(text) if text.contains('\n') => "the answer".to_owned(),
+                answer => answer.to_string(),
+            };
+            eprintln!(
+                "warning: {} part {}: {} {}",
+                day.name(),
+                part.number(),
+                shown,
+                warning
+            );
+        }
+        format.print(&Record {
+            day: day.number,
+            part: part.number(),
+            answer,
+            elapsed,
+        });
+    }
+    Ok(())
+}
+
+/// Times each stage of the day on its input and reports the change against
+/// the baseline, which is then updated with the new timings.
+fn bench_day(day: &Day, baseline: &mut Baseline) -> Result<(), String> {
+    let input = load_input(day, &InputSource::Puzzle)?;
+
+    for &stage in Stage::values() {
+        let time = bench::time_stage(day, &input.text, stage)
+            .map_err(|e| format!("{} {}:{}", day.name(), input.name, e))?;
+        match baseline
+            .get(day, stage)
+            .and_then(|base| bench::change(base, time))
+        {
+            Some(change) => println!(
+                "{} {:<6} {:>10.2?} ({:+.1}%)",
+                day.name(),
+                stage.to_string(),
+                time,
+                change
+            ),
+            None => println!("{} {:<6} {:>10.2?}", day.name(), stage.to_string(), time),
+        }
+        baseline.set(day, stage, time);
+    }
+    Ok(())
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => {
+            let day = days::get(day).ok_or(format!("no solution for day {}", day))?;
+            let parts = match &part {
+                Some(part) => std::slice::from_ref(part),
+                None => Part::values(),
+            };
+            run_day(day, parts, &input, format)
+        }
+        Command::RunAll { format } => {
+            let start = Instant::now();
+            for number in days::workspace_days() {
+                // Only the records go to stdout in JSON mode.
+                let note = |note: String| match format {
+                    OutputFormat::Human => println!("{}", note),
+                    OutputFormat::Json => eprintln!("{}", note),
+                };
+                match days::get(number) {
+                    Some(day) if !has_puzzle_input(day) => {
+                        note(format!("{}: no input", day.name()))
+                    }
+                    Some(day) => run_day(day, Part::values(), &InputSource::Puzzle, format)?,
+                    None => note(format!("day_{:02}: no solution", number)),
+                }
+            }
+            if format == OutputFormat::Human {
+                println!("total: {:.2?}", start.elapsed());
+            }
+            Ok(())
+        }
+        Command::Bench { day, save_baseline } => {
+            let days = match day {
+                Some(day) => vec![days::get(day).ok_or(format!("no solution for day {}", day))?],
+                None => days::workspace_days()
+                    .into_iter()
+                    .filter_map(days::get)
+                    .filter(|day| has_puzzle_input(day))
+                    .collect(),
+            };
+            let baseline_path = Baseline::default_path();
+            let mut baseline = Baseline::load(&baseline_path).map_err(|e| e.to_string())?;
+            for day in days {
+                bench_day(day, &mut baseline)?;
+            }
+            if save_baseline {
+                baseline.save(&baseline_path).map_err(|e| e.to_string())?;
+                println!("baseline saved to {}", baseline_path.display());
+            }
+            Ok(())
+        }
+        Command::Fetch { day } => {
+            let name = format!("day_{:02}", day);
+            let dir = solution::inputs_dir(day);
+            if !(1..=25).contains(&day) || !dir.parent().unwrap().is_dir() {
+                return Err(format!("no {} crate", name));
+            }
+            let config =
+                FetchConfig::load(&FetchConfig::default_path()).map_err(|e| e.to_string())?;
+            let dest = dir.join("input.txt");
+            match fetch::fetch(&config, day, &dest).map_err(|e| format!("{}: {}", name, e))? {
+                Fetched::Cached => println!("{}: input already cached in {}", name, dest.display()),
+                Fetched::Downloaded => println!("{}: input saved to {}", name, dest.display()),
+            }
+            Ok(())
+        }
+        Command::Ledger { day, submission } => {
+            let path = Ledger::path(day);
+            if !path.parent().unwrap().is_dir() {
+                return Err(format!("no day_{:02} crate", day));
+            }
+            let mut ledger = Ledger::load(&path).map_err(|e| e.to_string())?;
+            match submission {
+                None => print!("{}", std::fs::read_to_string(&path).unwrap_or_default()),
+                Some((part, submission)) => {
+                    match submission {
+                        Submission::Correct(answer) => ledger.record_correct(part, &answer),
+                        Submission::Rejected(answer, hint) => {
+                            ledger.record_rejected(part, &answer, hint)
+                        }
+                    }
+                    ledger.save(&path).map_err(|e| e.to_string())?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = run(command) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,92 @@
+use aoc::{
+    answers,
+    bench::Stage,
+    days::{self, Part},
+};
+
+/// Runs the day on every input file listed in its `inputs/answers.toml` and
+/// checks the answers, reporting all mismatches at once. Inputs listed
+/// without answers only have to parse, and every input has to be listed.
+fn check_day(number: u8) {
+    let day = days::get(number).unwrap();
+    let answers = answers::load(day)
+        .unwrap_or_else(|| panic!("{} has no inputs/answers.toml", day.name()))
+        .unwrap();
+
+    let mut failures = Vec::new();
+    let dir = aoc::inputs_dir(day);
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let name = entry.unwrap().file_name().to_string_lossy().into_owned();
+        if name != "answers.toml" && !answers.contains_key(&name) {
+            failures.push(format!("{} isn't listed in answers.toml", name));
+        }
+    }
+    for (file, expected) in &answers {
+        let path = dir.join(file);
+        let input = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("can't read {}: {}", path.display(), e));
+        if Part::values()
+            .iter()
+            .all(|&part| expected.get(part).is_none())
+        {
+            if let Err(e) = day.time(&input, Stage::Parse, 1) {
+                failures.push(format!("{}: {}", file, e));
+            }
+            continue;
+        }
+        for &part in Part::values() {
+            let Some(expected) = expected.get(part) else {
+                continue;
+            };
+            match day.solve(&input, part) {
+                Ok(answer) if answers::matches(&expected, &answer) => {}
+                Ok(answer) => failures.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    file,
+                    part.number(),
+                    expected,
+                    answer
+                )),
+                Err(e) => failures.push(format!("{} part {}: {}", file, part.number(), e)),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+macro_rules! answers_tests {
+    ($($name:ident => $number:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($number)
+            }
+        )*
+    };
+}
+
+answers_tests! {
+    day_01 => 1,
+    day_02 => 2,
+    day_03 => 3,
+    day_04 => 4,
+    day_05 => 5,
+    day_06 => 6,
+    day_07 => 7,
+    day_08 => 8,
+    day_09 => 9,
+    day_10 => 10,
+    day_11 => 11,
+    day_12 => 12,
+    day_13 => 13,
+    day_14 => 14,
+    day_15 => 15,
+    day_16 => 16,
+    day_18 => 18,
+    day_19 => 19,
+    day_20 => 20,
+    day_21 => 21,
+    day_23 => 23,
+    day_24 => 24,
+    day_25 => 25,
+}
@@ -0,0 +1,54 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    thread,
+};
+
+use aoc::fetch::{self, FetchConfig, Fetched};
+
+/// Serves a single request, returning its request line and headers.
+fn stand_in_server(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request: Vec<String> = BufReader::new(&stream)
+            .lines()
+            .map(Result::unwrap)
+            .take_while(|l| !l.is_empty())
+            .collect();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+        request
+    });
+    (base_url, server)
+}
+
+#[test]
+fn fetch_downloads_once() {
+    let (base_url, server) = stand_in_server("1\n2\n3\n");
+    let config = FetchConfig {
+        session: Some("cafe".to_owned()),
+        base_url: Some(base_url),
+    };
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let dest = dir.join("input.txt");
+
+    assert_eq!(
+        fetch::fetch(&config, 5, &dest).unwrap(),
+        Fetched::Downloaded
+    );
+    let request = server.join().unwrap();
+    assert_eq!(request[0], "GET /2021/day/5/input HTTP/1.1");
+    assert!(request.iter().any(|h| h == "Cookie: session=cafe"));
+    assert_eq!(std::fs::read_to_string(&dest).unwrap(), "1\n2\n3\n");
+
+    // The server is gone, so this only succeeds if nothing is downloaded.
+    assert_eq!(fetch::fetch(&config, 5, &dest).unwrap(), Fetched::Cached);
+    std::fs::remove_dir_all(dir).unwrap();
+}
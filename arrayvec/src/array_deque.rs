@@ -0,0 +1,383 @@
+use std::{
+    fmt,
+    mem::MaybeUninit,
+    ops::{Index, IndexMut},
+};
+
+use crate::CapacityError;
+
+pub type ArrayDequeIter<'a, T> = std::iter::Chain<std::slice::Iter<'a, T>, std::slice::Iter<'a, T>>;
+pub type ArrayDequeIterMut<'a, T> =
+    std::iter::Chain<std::slice::IterMut<'a, T>, std::slice::IterMut<'a, T>>;
+
+/// A double-ended queue stored inline in a ring buffer of `CAP` elements.
+pub struct ArrayDeque<T, const CAP: usize> {
+    /// Index in `array` of the front element.
+    head: usize,
+    len: usize,
+    array: [MaybeUninit<T>; CAP],
+}
+
+impl<T, const CAP: usize> ArrayDeque<T, CAP> {
+    pub const fn new() -> Self {
+        Self {
+            head: 0,
+            len: 0,
+            array: [const { MaybeUninit::uninit() }; CAP],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Index in `array` of the element at `index` in the deque, which must be
+    /// less than `CAP`.
+    fn physical(&self, index: usize) -> usize {
+        (self.head + index) % CAP
+    }
+
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(elem));
+        }
+        let tail = self.physical(self.len);
+        self.array[tail].write(elem);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        assert!(!self.is_full(), "push in a full ArrayDeque");
+        let _ = self.try_push_back(elem);
+    }
+
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(elem));
+        }
+        self.head = self.physical(CAP - 1);
+        self.array[self.head].write(elem);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        assert!(!self.is_full(), "push in a full ArrayDeque");
+        let _ = self.try_push_front(elem);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let front = self.head;
+        self.head = self.physical(1);
+        self.len -= 1;
+        unsafe { Some(self.array[front].assume_init_read()) }
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let back = self.physical(self.len);
+        unsafe { Some(self.array[back].assume_init_read()) }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        unsafe { Some(self.array[self.physical(index)].assume_init_ref()) }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let index = self.physical(index);
+        unsafe { Some(self.array[index].assume_init_mut()) }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// The elements in order, the second slice being the part that wrapped
+    /// around to the start of the buffer.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.ranges();
+        unsafe {
+            (
+                self.array[front].assume_init_ref(),
+                self.array[back].assume_init_ref(),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.ranges();
+        let (start, end) = self.array.split_at_mut(front.start);
+        unsafe {
+            (
+                end[..front.len()].assume_init_mut(),
+                start[back].assume_init_mut(),
+            )
+        }
+    }
+
+    fn ranges(&self) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        if self.head + self.len <= CAP {
+            (self.head..self.head + self.len, 0..0)
+        } else {
+            (self.head..CAP, 0..self.head + self.len - CAP)
+        }
+    }
+
+    pub fn iter(&self) -> ArrayDequeIter<'_, T> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back)
+    }
+
+    pub fn iter_mut(&mut self) -> ArrayDequeIterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back)
+    }
+
+    /// Moves the first `n` elements to the back. Only moves the head when
+    /// the deque is full, otherwise moves `min(n, len - n)` elements.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "rotating by more than the length");
+        if self.is_full() {
+            self.head = (self.head + n) % CAP.max(1);
+        } else if n <= self.len - n {
+            self.move_front_to_back(n);
+        } else {
+            self.move_back_to_front(self.len - n);
+        }
+    }
+
+    /// Moves the last `n` elements to the front, see `rotate_left`.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "rotating by more than the length");
+        self.rotate_left(self.len - n)
+    }
+
+    /// Moves `n` elements from the front to the free slots after the back,
+    /// the deque must not be full.
+    fn move_front_to_back(&mut self, n: usize) {
+        for _ in 0..n {
+            let (front, back) = (self.physical(0), self.physical(self.len));
+            // SAFETY: the front is initialized, and the slot after the back is
+            // free since the deque isn't full. The front slot is left out of
+            // the deque by moving the head.
+            let e = unsafe { self.array[front].assume_init_read() };
+            self.array[back].write(e);
+            self.head = self.physical(1);
+        }
+    }
+
+    /// Moves `n` elements from the back to the free slots before the front,
+    /// the deque must not be full.
+    fn move_back_to_front(&mut self, n: usize) {
+        for _ in 0..n {
+            let (back, front) = (self.physical(self.len - 1), self.physical(CAP - 1));
+            // SAFETY: as in `move_front_to_back`, the back slot is left out of
+            // the deque by moving the head back.
+            let e = unsafe { self.array[back].assume_init_read() };
+            self.array[front].write(e);
+            self.head = front;
+        }
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop_back();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+}
+
+impl<T, const CAP: usize> Drop for ArrayDeque<T, CAP> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T, const CAP: usize> Default for ArrayDeque<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const CAP: usize> Clone for ArrayDeque<T, CAP> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const CAP: usize> Index<usize> for ArrayDeque<T, CAP> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(e) => e,
+            None => panic!("index {} out of bounds (len is {})", index, self.len),
+        }
+    }
+}
+
+impl<T, const CAP: usize> IndexMut<usize> for ArrayDeque<T, CAP> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(e) => e,
+            None => panic!("index {} out of bounds (len is {})", index, len),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const CAP: usize> fmt::Debug for ArrayDeque<T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const CAP: usize> PartialEq for ArrayDeque<T, CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const CAP: usize> Eq for ArrayDeque<T, CAP> {}
+
+impl<T: std::hash::Hash, const CAP: usize> std::hash::Hash for ArrayDeque<T, CAP> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|e| e.hash(state));
+    }
+}
+
+impl<T, const CAP: usize> FromIterator<T> for ArrayDeque<T, CAP> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut deque = Self::new();
+        for e in iter {
+            deque.push_back(e);
+        }
+        deque
+    }
+}
+
+/// Owning iterator of an `ArrayDeque`, from front to back.
+pub struct ArrayDequeIntoIter<T, const CAP: usize>(ArrayDeque<T, CAP>);
+
+impl<T, const CAP: usize> Iterator for ArrayDequeIntoIter<T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T, const CAP: usize> DoubleEndedIterator for ArrayDequeIntoIter<T, CAP> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+}
+
+impl<T, const CAP: usize> ExactSizeIterator for ArrayDequeIntoIter<T, CAP> {}
+
+impl<T, const CAP: usize> std::iter::FusedIterator for ArrayDequeIntoIter<T, CAP> {}
+
+impl<T, const CAP: usize> IntoIterator for ArrayDeque<T, CAP> {
+    type Item = T;
+    type IntoIter = ArrayDequeIntoIter<T, CAP>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArrayDequeIntoIter(self)
+    }
+}
+
+impl<'a, T, const CAP: usize> IntoIterator for &'a ArrayDeque<T, CAP> {
+    type Item = &'a T;
+    type IntoIter = ArrayDequeIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAP: usize> IntoIterator for &'a mut ArrayDeque<T, CAP> {
+    type Item = &'a mut T;
+    type IntoIter = ArrayDequeIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_deque() {
+        let mut deque = ArrayDeque::<u32, 4>::new();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_front(0);
+        deque.push_back(3);
+        assert_eq!(deque.try_push_back(4), Err(CapacityError::new(4)));
+        assert_eq!(deque.as_slices(), (&[0, 1][..], &[2, 3][..]));
+
+        deque.rotate_left(1);
+        deque[3] += 10;
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 10]);
+        assert_eq!((deque.pop_front(), deque.pop_back()), (Some(1), Some(10)));
+        deque.rotate_right(1);
+        assert_eq!(deque.into_iter().rev().collect::<Vec<_>>(), [2, 3]);
+    }
+
+    #[test]
+    fn rotate_not_full() {
+        let mut deque: ArrayDeque<String, 8> = (0..5).map(|i| i.to_string()).collect();
+        let rotated = |deque: &ArrayDeque<String, 8>| deque.iter().cloned().collect::<String>();
+        deque.rotate_left(1);
+        assert_eq!(rotated(&deque), "12340");
+        deque.rotate_left(4);
+        assert_eq!(rotated(&deque), "01234");
+        deque.rotate_right(2);
+        assert_eq!(rotated(&deque), "34012");
+        deque.rotate_right(4);
+        assert_eq!(rotated(&deque), "40123");
+        deque.rotate_left(5);
+        deque.rotate_right(0);
+        assert_eq!(rotated(&deque), "40123");
+        assert_eq!(deque.len(), 5);
+    }
+}
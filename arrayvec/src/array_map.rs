@@ -0,0 +1,319 @@
+use std::{borrow::Borrow, fmt};
+
+use crate::{ArrayVec, ArrayVecIntoIter, CapacityError, Length};
+
+/// A map of at most `CAP` entries, searched linearly: lookups compare the
+/// key with every entry in turn, without hashing or probing. For small key
+/// sets it is cheaper than hashing, and doesn't allocate.
+pub struct ArrayMap<K, V, const CAP: usize, L: Length = u8> {
+    entries: ArrayVec<(K, V), CAP, L>,
+}
+
+impl<K: Eq, V, const CAP: usize, L: Length> ArrayMap<K, V, CAP, L> {
+    pub const fn new() -> Self {
+        Self {
+            entries: ArrayVec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.entries.is_full()
+    }
+
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let (k, v) = &self.entries[self.position(key)?];
+        Some((k, v))
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let i = self.position(key)?;
+        Some(&mut self.entries[i].1)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Inserts or replaces the value of `key`, returning the previous one. A
+    /// new key that doesn't fit is given back with its value.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        match self.entry(key) {
+            ArrayMapEntry::Occupied(mut e) => Ok(Some(e.insert(value))),
+            ArrayMapEntry::Vacant(e) => {
+                let VacantEntry { map, key } = e;
+                map.entries.try_push((key, value))?;
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(previous) => previous,
+            Err(_) => panic!("insertion in a full ArrayMap"),
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let i = self.position(key)?;
+        Some(self.entries.swap_remove(i))
+    }
+
+    pub fn entry(&mut self, key: K) -> ArrayMapEntry<'_, K, V, CAP, L> {
+        match self.position(&key) {
+            Some(index) => ArrayMapEntry::Occupied(OccupiedEntry { map: self, index }),
+            None => ArrayMapEntry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.entries.drain_filter(|(k, v)| !f(k, v)).for_each(drop);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
+
+impl<K, V, const CAP: usize, L: Length> ArrayMap<K, V, CAP, L> {
+    /// The entries, in insertion order until one is removed.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = (&K, &mut V)> {
+        self.entries.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    pub fn values_mut(&mut self) -> impl ExactSizeIterator<Item = &mut V> {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+}
+
+pub enum ArrayMapEntry<'a, K, V, const CAP: usize, L: Length = u8> {
+    Occupied(OccupiedEntry<'a, K, V, CAP, L>),
+    Vacant(VacantEntry<'a, K, V, CAP, L>),
+}
+
+pub struct OccupiedEntry<'a, K, V, const CAP: usize, L: Length = u8> {
+    map: &'a mut ArrayMap<K, V, CAP, L>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, const CAP: usize, L: Length = u8> {
+    map: &'a mut ArrayMap<K, V, CAP, L>,
+    key: K,
+}
+
+impl<'a, K, V, const CAP: usize, L: Length> ArrayMapEntry<'a, K, V, CAP, L> {
+    pub fn key(&self) -> &K {
+        match self {
+            ArrayMapEntry::Occupied(e) => e.key(),
+            ArrayMapEntry::Vacant(e) => &e.key,
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            ArrayMapEntry::Occupied(e) => e.into_mut(),
+            ArrayMapEntry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let ArrayMapEntry::Occupied(e) = &mut self {
+            f(e.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V, const CAP: usize, L: Length> OccupiedEntry<'a, K, V, CAP, L> {
+    pub fn key(&self) -> &K {
+        &self.map.entries[self.index].0
+    }
+
+    pub fn get(&self) -> &V {
+        &self.map.entries[self.index].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.entries[self.index].1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.entries[self.index].1
+    }
+
+    /// Replaces the value, returning the previous one.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.map.entries.swap_remove(self.index).1
+    }
+}
+
+impl<'a, K, V, const CAP: usize, L: Length> VacantEntry<'a, K, V, CAP, L> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Panics if the map is full.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let entries = &mut self.map.entries;
+        entries.push((self.key, value));
+        &mut entries.last_mut().unwrap().1
+    }
+}
+
+impl<K: Eq, V, const CAP: usize, L: Length> Default for ArrayMap<K, V, CAP, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, const CAP: usize, L: Length> Clone for ArrayMap<K, V, CAP, L> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const CAP: usize, L: Length> fmt::Debug
+    for ArrayMap<K, V, CAP, L>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Maps are equal if they have the same entries, in any order.
+impl<K: Eq, V: PartialEq, const CAP: usize, L: Length> PartialEq for ArrayMap<K, V, CAP, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Eq, V: Eq, const CAP: usize, L: Length> Eq for ArrayMap<K, V, CAP, L> {}
+
+impl<K: Eq, V, const CAP: usize, L: Length> FromIterator<(K, V)> for ArrayMap<K, V, CAP, L> {
+    fn from_iter<U: IntoIterator<Item = (K, V)>>(iter: U) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq, V, const CAP: usize, L: Length> Extend<(K, V)> for ArrayMap<K, V, CAP, L> {
+    fn extend<U: IntoIterator<Item = (K, V)>>(&mut self, iter: U) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V, const CAP: usize, L: Length> IntoIterator for ArrayMap<K, V, CAP, L> {
+    type Item = (K, V);
+    type IntoIter = ArrayVecIntoIter<(K, V), CAP, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_map() {
+        let mut map = ArrayMap::<&str, u32, 3>::new();
+        assert_eq!(map.insert("a", 1), None);
+        *map.entry("b").or_insert(0) += 2;
+        *map.entry("b").or_insert(0) += 2;
+        assert_eq!(map.insert("a", 3), Some(1));
+        map.insert("c", 5);
+        assert!(map.try_insert("d", 0).is_err());
+        assert_eq!(
+            (map.get("a"), map.get("b"), map.get("d")),
+            (Some(&3), Some(&4), None)
+        );
+
+        assert_eq!(map.remove("a"), Some(3));
+        map.retain(|_, v| *v > 4);
+        let mut other = ArrayMap::new();
+        other.insert("c", 5);
+        assert_eq!(map, other);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), [("c", 5)]);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{borrow::Borrow, fmt, ops::Deref};
+
+use crate::{CopyArrayVec, Length};
+
+/// A string stored inline, holding at most `CAP` bytes.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct ArrayStr<const CAP: usize, L: Length = u8>(CopyArrayVec<u8, CAP, L>);
+
+/// Error when a string doesn't fit in an `ArrayStr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrCapacityError {
+    needed: usize,
+    capacity: usize,
+}
+
+impl StrCapacityError {
+    /// The length in bytes the string would have needed.
+    pub fn needed(&self) -> usize {
+        self.needed
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for StrCapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a string of {} bytes doesn't fit in a capacity of {}",
+            self.needed, self.capacity
+        )
+    }
+}
+
+impl std::error::Error for StrCapacityError {}
+
+impl<const CAP: usize, L: Length> ArrayStr<CAP, L> {
+    pub const fn new() -> Self {
+        Self(CopyArrayVec::new())
+    }
+
+    pub fn as_str(&self) -> &str {
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), StrCapacityError> {
+        let needed = self.len() + s.len();
+        self.0
+            .try_extend_from_slice(s.as_bytes())
+            .map_err(|_| StrCapacityError {
+                needed,
+                capacity: CAP,
+            })
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        if let Err(e) = self.try_push_str(s) {
+            panic!("{}", e)
+        }
+    }
+
+    pub fn try_push(&mut self, c: char) -> Result<(), StrCapacityError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.chars().next_back()?;
+        let len = self.len() - c.len_utf8();
+        self.0.truncate(len);
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes, which must be on a char boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.is_char_boundary(len), "truncating inside a char");
+            self.0.truncate(len);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+
+impl<const CAP: usize, L: Length> Default for ArrayStr<CAP, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize, L: Length> std::str::FromStr for ArrayStr<CAP, L> {
+    type Err = StrCapacityError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut new = Self::new();
+        new.try_push_str(s)?;
+        Ok(new)
+    }
+}
+
+impl<const CAP: usize, L: Length> TryFrom<&str> for ArrayStr<CAP, L> {
+    type Error = StrCapacityError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const CAP: usize, L: Length> TryFrom<char> for ArrayStr<CAP, L> {
+    type Error = StrCapacityError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let mut new = Self::new();
+        new.try_push(c)?;
+        Ok(new)
+    }
+}
+
+impl<const CAP: usize, L: Length> Deref for ArrayStr<CAP, L> {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        // Only whole `str`s are pushed and only whole chars are removed.
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+impl<const CAP: usize, L: Length> AsRef<str> for ArrayStr<CAP, L> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<const CAP: usize, L: Length> Borrow<str> for ArrayStr<CAP, L> {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl<const CAP: usize, L: Length> fmt::Write for ArrayStr<CAP, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const CAP: usize, L: Length> fmt::Debug for ArrayStr<CAP, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<const CAP: usize, L: Length> fmt::Display for ArrayStr<CAP, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<const CAP: usize, L: Length> std::hash::Hash for ArrayStr<CAP, L> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
+impl<const CAP: usize, L: Length> PartialEq<str> for ArrayStr<CAP, L> {
+    fn eq(&self, other: &str) -> bool {
+        self.deref() == other
+    }
+}
+
+impl<const CAP: usize, L: Length> PartialEq<&str> for ArrayStr<CAP, L> {
+    fn eq(&self, other: &&str) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<const CAP: usize, L: Length> PartialEq<ArrayStr<CAP, L>> for str {
+    fn eq(&self, other: &ArrayStr<CAP, L>) -> bool {
+        self == other.deref()
+    }
+}
+
+impl<const CAP: usize, L: Length> PartialOrd for ArrayStr<CAP, L> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CAP: usize, L: Length> Ord for ArrayStr<CAP, L> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_str() {
+        use std::fmt::Write;
+
+        let mut s = ArrayStr::<6>::try_from('é').unwrap();
+        s.push_str("t");
+        write!(s, "{}", 42).unwrap();
+        assert_eq!(s, "ét42");
+        assert_eq!(s.pop(), Some('2'));
+        assert!(write!(s, "abc").is_err());
+        assert_eq!(s.to_string(), "ét4");
+
+        let err = ArrayStr::<2>::try_from("abc").unwrap_err();
+        assert_eq!((err.needed(), err.capacity()), (3, 2));
+        assert!(ArrayStr::<2>::try_from("ab").unwrap() < ArrayStr::try_from("b").unwrap());
+    }
+}
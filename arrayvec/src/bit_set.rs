@@ -0,0 +1,308 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign},
+};
+
+/// Integer type storing the bits of a `BitSet`. The default `u8` keeps small
+/// sets compact, sets of more than 8 bits need a wider type, as in
+/// `BitSet<16, u16>`.
+pub trait Word: Copy + Eq + Ord + std::hash::Hash + Default + fmt::Debug {
+    const ZERO: Self;
+    const BITS: usize;
+
+    fn to_u128(self) -> u128;
+
+    /// `bits` must fit in `Self::BITS`.
+    fn from_u128(bits: u128) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($int:ty),*) => {
+        $(
+            impl Word for $int {
+                const ZERO: Self = 0;
+                const BITS: usize = <$int>::BITS as usize;
+
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+
+                fn from_u128(bits: u128) -> Self {
+                    bits as $int
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, u64, u128);
+
+/// A set of integers in `0..BITS`, stored as the bits of a `W`.
+///
+/// `BITS` can't be more than 128: sizing an array of words from `BITS` needs
+/// const generic expressions, which are not stable, and a `BITS` too big
+/// for `W` doesn't compile:
+///
+/// ```compile_fail
+/// let set = arrayvec::BitSet::<200>::default();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet<const BITS: usize, W: Word = u8> {
+    bits: W,
+}
+
+impl<const BITS: usize, W: Word> BitSet<BITS, W> {
+    const FITS: () = assert!(BITS <= W::BITS, "BITS doesn't fit in the BitSet word");
+
+    const MASK: u128 = if BITS == 128 {
+        u128::MAX
+    } else {
+        (1 << BITS) - 1
+    };
+
+    pub const fn new() -> Self {
+        let () = Self::FITS;
+        Self { bits: W::ZERO }
+    }
+
+    fn raw(&self) -> u128 {
+        self.bits.to_u128()
+    }
+
+    fn from_raw(bits: u128) -> Self {
+        Self {
+            bits: W::from_u128(bits),
+        }
+    }
+
+    /// The set of all of `0..BITS`.
+    pub fn full() -> Self {
+        let () = Self::FITS;
+        Self::from_raw(Self::MASK)
+    }
+
+    /// The set of the bit positions set in `bits`, which must be below `BITS`.
+    pub fn from_bits(bits: W) -> Self {
+        let () = Self::FITS;
+        assert!(bits.to_u128() & !Self::MASK == 0, "bits set above {}", BITS);
+        Self { bits }
+    }
+
+    pub fn bits(&self) -> W {
+        self.bits
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw().count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw() == 0
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        i < BITS && self.raw() & (1 << i) != 0
+    }
+
+    /// Adds `i`, returning whether it wasn't in the set already.
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(i < BITS, "{} is out of a BitSet of {} bits", i, BITS);
+        let absent = !self.contains(i);
+        *self = Self::from_raw(self.raw() | 1 << i);
+        absent
+    }
+
+    /// Removes `i`, returning whether it was in the set.
+    pub fn remove(&mut self, i: usize) -> bool {
+        let present = self.contains(i);
+        if present {
+            *self = Self::from_raw(self.raw() & !(1 << i));
+        }
+        present
+    }
+
+    pub fn clear(&mut self) {
+        self.bits = W::ZERO
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_raw(self.raw() | other.raw())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_raw(self.raw() & other.raw())
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::from_raw(self.raw() & !other.raw())
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        Self::from_raw(self.raw() ^ other.raw())
+    }
+
+    /// The elements of `0..BITS` not in the set.
+    pub fn complement(&self) -> Self {
+        Self::from_raw(!self.raw() & Self::MASK)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.raw() & other.raw() == self.raw()
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.raw() & other.raw() == 0
+    }
+
+    /// The elements in increasing order.
+    pub fn iter(&self) -> BitSetIter {
+        BitSetIter { bits: self.raw() }
+    }
+}
+
+impl<const BITS: usize, W: Word> Default for BitSet<BITS, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize, W: Word> fmt::Debug for BitSet<BITS, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const BITS: usize, W: Word> FromIterator<usize> for BitSet<BITS, W> {
+    fn from_iter<U: IntoIterator<Item = usize>>(iter: U) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const BITS: usize, W: Word> Extend<usize> for BitSet<BITS, W> {
+    fn extend<U: IntoIterator<Item = usize>>(&mut self, iter: U) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+/// Iterator over the elements of a `BitSet`.
+#[derive(Debug, Clone)]
+pub struct BitSetIter {
+    bits: u128,
+}
+
+impl Iterator for BitSetIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.bits == 0 {
+            return None;
+        }
+        let i = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(i as usize)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BitSetIter {
+    fn next_back(&mut self) -> Option<usize> {
+        if self.bits == 0 {
+            return None;
+        }
+        let i = 127 - self.bits.leading_zeros();
+        self.bits &= !(1 << i);
+        Some(i as usize)
+    }
+}
+
+impl ExactSizeIterator for BitSetIter {}
+
+impl std::iter::FusedIterator for BitSetIter {}
+
+impl<const BITS: usize, W: Word> IntoIterator for BitSet<BITS, W> {
+    type Item = usize;
+    type IntoIter = BitSetIter;
+
+    fn into_iter(self) -> BitSetIter {
+        self.iter()
+    }
+}
+
+impl<const BITS: usize, W: Word> IntoIterator for &BitSet<BITS, W> {
+    type Item = usize;
+    type IntoIter = BitSetIter;
+
+    fn into_iter(self) -> BitSetIter {
+        self.iter()
+    }
+}
+
+macro_rules! impl_set_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $method:ident) => {
+        impl<const BITS: usize, W: Word> $op for BitSet<BITS, W> {
+            type Output = Self;
+
+            fn $fn(self, other: Self) -> Self {
+                self.$method(&other)
+            }
+        }
+
+        impl<const BITS: usize, W: Word> $op_assign for BitSet<BITS, W> {
+            fn $fn_assign(&mut self, other: Self) {
+                *self = self.$method(&other)
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+impl_set_op!(Sub, sub, SubAssign, sub_assign, difference);
+impl_set_op!(
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    symmetric_difference
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_set() {
+        let a: BitSet<10, u16> = [1, 3, 5, 9].into_iter().collect();
+        let b: BitSet<10, u16> = [3, 4, 5].into_iter().collect();
+        assert_eq!((a | b).iter().collect::<Vec<_>>(), [1, 3, 4, 5, 9]);
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!((a - b).iter().rev().collect::<Vec<_>>(), [9, 1]);
+        assert_eq!((a ^ b).len(), 3);
+        assert_eq!(a.complement().len(), 6);
+        assert!((a & b).is_subset(&b) && !a.is_subset(&b));
+        assert!(a.complement().is_disjoint(&a));
+
+        assert_eq!(std::mem::size_of::<BitSet<7>>(), 1);
+        assert_eq!(BitSet::<7>::full().bits(), 0x7f);
+
+        let mut full = BitSet::<128, u128>::full();
+        assert_eq!(full.len(), 128);
+        assert!(full.remove(127) && !full.remove(127));
+        assert!(!full.contains(127) && !full.contains(200));
+
+        let empty = BitSet::<10, u16>::default();
+        assert!((0..200).all(|i| !empty.contains(i)));
+    }
+}
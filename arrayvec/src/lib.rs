@@ -1,17 +1,157 @@
-#![feature(maybe_uninit_uninit_array)]
-#![feature(maybe_uninit_array_assume_init)]
-#![feature(maybe_uninit_extra)]
-#![feature(maybe_uninit_slice)]
+#![cfg_attr(feature = "nightly", feature(maybe_uninit_array_assume_init))]
 
-use std::{mem::MaybeUninit, ops::Deref};
+use std::{
+    mem::MaybeUninit,
+    ops::{Bound, Deref, RangeBounds},
+    ptr,
+};
 
-#[derive(Copy)]
-pub struct ArrayVec<T, const CAP: usize> {
-    length: u8,
+mod array_deque;
+mod array_map;
+mod array_str;
+mod bit_set;
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+pub use array_deque::{ArrayDeque, ArrayDequeIntoIter, ArrayDequeIter, ArrayDequeIterMut};
+pub use array_map::{ArrayMap, ArrayMapEntry, OccupiedEntry, VacantEntry};
+pub use array_str::{ArrayStr, StrCapacityError};
+pub use bit_set::{BitSet, BitSetIter, Word};
+pub use drain::Drain;
+pub use drain_filter::DrainFilter;
+
+/// Integer type storing the length of an `ArrayVec`. The default `u8` keeps
+/// small vectors compact, bigger capacities need a wider type, as in
+/// `ArrayVec<u8, 1024, u16>`.
+///
+/// The unsafe code in this crate trusts `to_usize` to give back the length
+/// it was built from, so the trait is sealed: only `u8`, `u16`, `u32` and
+/// `usize` implement it.
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+/// struct Liar;
+///
+/// impl arrayvec::Length for Liar {
+///     const ZERO: Self = Liar;
+///     const MAX: usize = 4;
+///
+///     fn to_usize(self) -> usize {
+///         4
+///     }
+///
+///     fn from_usize(_: usize) -> Self {
+///         Liar
+///     }
+/// }
+/// ```
+pub trait Length: sealed::Sealed + Copy + Eq + Ord + std::hash::Hash + std::fmt::Debug {
+    const ZERO: Self;
+    const MAX: usize;
+
+    fn to_usize(self) -> usize;
+
+    /// Panics if `n` is greater than `Self::MAX`.
+    fn from_usize(n: usize) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_length {
+    ($($int:ty),*) => {
+        $(
+            impl sealed::Sealed for $int {}
+
+            impl Length for $int {
+                const ZERO: Self = 0;
+                const MAX: usize = <$int>::MAX as usize;
+
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+
+                fn from_usize(n: usize) -> Self {
+                    assert!(
+                        n <= <Self as Length>::MAX,
+                        "length {} doesn't fit in a {}",
+                        n,
+                        stringify!($int)
+                    );
+                    n as $int
+                }
+            }
+        )*
+    };
+}
+
+impl_length!(u8, u16, u32, usize);
+
+/// Creates an `ArrayVec` from its elements, like `vec!`. The capacity comes
+/// from the type, and must be at least the number of elements:
+///
+/// ```
+/// # use arrayvec::{arrayvec, ArrayVec};
+/// let v: ArrayVec<u8, 4> = arrayvec![1, 2, 3];
+/// let zeros: ArrayVec<u8, 8> = arrayvec![0; 5];
+/// # assert_eq!((v.len(), zeros.len()), (3, 5));
+/// ```
+#[macro_export]
+macro_rules! arrayvec {
+    ($elem:expr; $n:expr) => {
+        $crate::ArrayVec::from([$elem; $n])
+    };
+    ($($x:expr),* $(,)?) => {
+        $crate::ArrayVec::from([$($x),*])
+    };
+}
+
+/// Error of the fallible `ArrayVec` operations when there is not enough
+/// capacity left. It gives back the element that didn't fit.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    pub fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    pub fn element(self) -> T {
+        self.element
+    }
+
+    /// Drops the element, e.g. to return the error as a `Box<dyn Error>`.
+    pub fn simplify(self) -> CapacityError {
+        CapacityError { element: () }
+    }
+}
+
+impl<T> std::fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CapacityError: insufficient capacity")
+    }
+}
+
+impl<T> std::fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+impl<T> std::error::Error for CapacityError<T> {}
+
+/// A vector stored inline, holding at most `CAP` elements. The initialized
+/// elements are dropped with it, see `CopyArrayVec` for a `Copy` version.
+#[repr(C)]
+pub struct ArrayVec<T, const CAP: usize, L: Length = u8> {
+    length: L,
     array: [MaybeUninit<T>; CAP],
 }
 
-impl<T: std::fmt::Debug, const CAP: usize> std::fmt::Debug for ArrayVec<T, CAP> {
+impl<T: std::fmt::Debug, const CAP: usize, L: Length> std::fmt::Debug for ArrayVec<T, CAP, L> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use std::fmt::Write;
         f.write_char('[')?;
@@ -28,20 +168,26 @@ impl<T: std::fmt::Debug, const CAP: usize> std::fmt::Debug for ArrayVec<T, CAP>
     }
 }
 
-impl<T, const CAP: usize> std::ops::Deref for ArrayVec<T, CAP> {
+impl<T, const CAP: usize, L: Length> std::ops::Deref for ArrayVec<T, CAP, L> {
     type Target = [T];
     fn deref(&self) -> &Self::Target {
-        unsafe { MaybeUninit::slice_assume_init_ref(&self.array[0..self.length as usize]) }
+        unsafe { self.array[0..self.length.to_usize()].assume_init_ref() }
     }
 }
 
-impl<T, const CAP: usize> std::ops::DerefMut for ArrayVec<T, CAP> {
+impl<T, const CAP: usize, L: Length> std::ops::DerefMut for ArrayVec<T, CAP, L> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { MaybeUninit::slice_assume_init_mut(&mut self.array[0..self.length as usize]) }
+        unsafe { self.array[0..self.length.to_usize()].assume_init_mut() }
     }
 }
 
-impl<T: Clone, const CAP: usize> Clone for ArrayVec<T, CAP> {
+impl<T, const CAP: usize, L: Length> Drop for ArrayVec<T, CAP, L> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: Clone, const CAP: usize, L: Length> Clone for ArrayVec<T, CAP, L> {
     fn clone(&self) -> Self {
         let mut new = Self::new();
         for e in self.iter() {
@@ -51,7 +197,7 @@ impl<T: Clone, const CAP: usize> Clone for ArrayVec<T, CAP> {
     }
 }
 
-impl<T: PartialEq, const CAP: usize> PartialEq for ArrayVec<T, CAP> {
+impl<T: PartialEq, const CAP: usize, L: Length> PartialEq for ArrayVec<T, CAP, L> {
     fn eq(&self, other: &Self) -> bool {
         if self.len() != other.len() {
             return false;
@@ -65,22 +211,27 @@ impl<T: PartialEq, const CAP: usize> PartialEq for ArrayVec<T, CAP> {
     }
 }
 
-impl<T: Eq, const CAP: usize> Eq for ArrayVec<T, CAP> {}
+impl<T: Eq, const CAP: usize, L: Length> Eq for ArrayVec<T, CAP, L> {}
 
-impl<T, const CAP: usize> ArrayVec<T, CAP> {
-    pub fn new() -> Self {
-        assert!(CAP <= u8::MAX as usize);
+impl<T, const CAP: usize, L: Length> ArrayVec<T, CAP, L> {
+    /// Fails to compile if `CAP` doesn't fit in the length type:
+    ///
+    /// ```compile_fail
+    /// let v = arrayvec::ArrayVec::<u8, 256>::new();
+    /// ```
+    pub const fn new() -> Self {
+        const { assert!(CAP <= L::MAX, "capacity too big for the length type") };
         Self {
-            length: 0,
-            array: MaybeUninit::uninit_array(),
+            length: L::ZERO,
+            array: [const { MaybeUninit::uninit() }; CAP],
         }
     }
 
-    pub fn drain_filter<F>(&mut self, filter: F) -> drain_filter::DrainFilter<'_, T, F, CAP>
+    pub fn drain_filter<F>(&mut self, filter: F) -> drain_filter::DrainFilter<'_, T, F, CAP, L>
     where
         F: FnMut(&mut T) -> bool,
     {
-        let old_len = self.len() as u8;
+        let old_len = self.len();
 
         // Guard against us getting leaked (leak amplification)
         unsafe {
@@ -98,52 +249,330 @@ impl<T, const CAP: usize> ArrayVec<T, CAP> {
     }
 
     pub fn pop(&mut self) -> Option<T> {
-        if self.length == 0 {
+        let len = self.len();
+        if len == 0 {
             None
         } else {
             unsafe {
-                self.length -= 1;
-                Some(self.array[self.length as usize].assume_init_read())
+                self.set_len(len - 1);
+                Some(self.array[len - 1].assume_init_read())
             }
         }
     }
 
-    pub unsafe fn set_len(&mut self, new_len: u8) {
-        self.length = new_len;
+    /// # Safety
+    ///
+    /// `new_len` must be less than or equal to `CAP`, and the elements at
+    /// `old_len..new_len` must be initialized.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.length = L::from_usize(new_len);
     }
 
+    /// # Safety
+    ///
+    /// The vector must not be full.
     pub unsafe fn push_unchecked(&mut self, elem: T) {
-        self.array
-            .get_unchecked_mut(self.length as usize)
-            .write(elem);
-        self.length += 1;
+        let len = self.len();
+        self.array.get_unchecked_mut(len).write(elem);
+        self.set_len(len + 1);
     }
 
     pub fn push(&mut self, elem: T) {
-        assert!((self.length as usize) < CAP);
+        assert!(self.len() < CAP);
         unsafe { self.push_unchecked(elem) }
     }
 
-    pub fn to_array<const LEN: usize>(self) -> [T; LEN] {
-        assert!(LEN <= self.length as usize);
+    pub fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(elem));
+        }
+        unsafe { self.push_unchecked(elem) };
+        Ok(())
+    }
+
+    /// Pushes the elements of `iter` until it is exhausted or the vector is
+    /// full, in which case the first element that didn't fit is returned.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), CapacityError<T>> {
+        for e in iter {
+            self.try_push(e)?;
+        }
+        Ok(())
+    }
+
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError<T>> {
+        let mut vec = Self::new();
+        vec.try_extend(iter)?;
+        Ok(vec)
+    }
+
+    /// Moves the first `LEN` elements to an array, the others are dropped.
+    pub fn to_array<const LEN: usize>(mut self) -> [T; LEN] {
+        assert!(LEN <= self.len());
+        self.truncate(LEN);
+        unsafe {
+            self.set_len(0);
+            let mut arr: [MaybeUninit<T>; LEN] = [const { MaybeUninit::uninit() }; LEN];
+            ptr::copy_nonoverlapping(self.as_ptr(), arr.as_mut_ptr().cast::<T>(), LEN);
+
+            #[cfg(feature = "nightly")]
+            return MaybeUninit::array_assume_init(arr);
+            // `[MaybeUninit<T>; LEN]` has the same layout as `[T; LEN]`.
+            #[cfg(not(feature = "nightly"))]
+            return std::ptr::read(arr.as_ptr().cast::<[T; LEN]>());
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == CAP
+    }
+
+    /// Pointer to the start of the storage, valid for `CAP` elements and not
+    /// just for the initialized ones like the slice's `as_mut_ptr`.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.array.as_mut_ptr().cast()
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.array.as_ptr().cast()
+    }
+
+    pub fn insert(&mut self, index: usize, elem: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len
+        );
+        assert!(len < CAP, "insertion in a full ArrayVec");
         unsafe {
-            let original_array = self.array;
+            let p = self.as_mut_ptr().add(index);
+            ptr::copy(p, p.add(1), len - index);
+            p.write(elem);
+            self.set_len(len + 1);
+        }
+    }
 
-            let mut arr: [MaybeUninit<T>; LEN] = MaybeUninit::uninit_array();
-            for i in 0..LEN {
-                arr[i].write(original_array[i].assume_init_read());
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            let elem = p.read();
+            ptr::copy(p.add(1), p, len - index - 1);
+            self.set_len(len - 1);
+            elem
+        }
+    }
+
+    /// Removes the element at `index` and replaces it with the last one.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "swap_remove index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+        unsafe {
+            let base = self.as_mut_ptr();
+            let elem = base.add(index).read();
+            ptr::copy(base.add(len - 1), base.add(index), 1);
+            self.set_len(len - 1);
+            elem
+        }
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len();
+        if len >= old_len {
+            return;
+        }
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), old_len - len);
+            // Shorten first, so a panicking drop doesn't drop an element twice.
+            self.set_len(len);
+            ptr::drop_in_place(tail);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.drain_filter(|e| !f(e)).for_each(drop);
+    }
+
+    /// Removes the elements in `range`, returned by the iterator. The tail is
+    /// moved back when the iterator is dropped.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> drain::Drain<'_, T, CAP, L> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).unwrap_or_else(|| {
+                panic!("drain range after {} out of bounds (len is {})", n, len)
+            }),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).unwrap_or_else(|| {
+                panic!(
+                    "drain range {}..={} out of bounds (len is {})",
+                    start, n, len
+                )
+            }),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "drain range {}..{} out of bounds (len is {})",
+            start,
+            end,
+            len
+        );
+
+        // Same as `drain_filter`, the drained and tail elements are out of the
+        // vector until the iterator is dropped.
+        unsafe {
+            self.set_len(start);
+        }
+
+        drain::Drain {
+            vec: self,
+            idx: start,
+            end,
+            tail_start: end,
+            tail_len: len - end,
+        }
+    }
+
+    /// Splits the vector in two, `self` keeping the elements before `at`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {}) should be <= len (is {})",
+            at,
+            len
+        );
+        let mut other = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), len - at);
+            self.set_len(at);
+            other.set_len(len - at);
+        }
+        other
+    }
+
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes the consecutive elements for which `same_bucket(elem, previous)`
+    /// is true, keeping the first one of each run.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        struct FillGapOnDrop<'a, T, const CAP: usize, L: Length> {
+            /// The index of the next element to compare.
+            read: usize,
+            /// The length of the deduplicated prefix.
+            write: usize,
+            old_len: usize,
+            vec: &'a mut ArrayVec<T, CAP, L>,
+        }
+
+        impl<T, const CAP: usize, L: Length> Drop for FillGapOnDrop<'_, T, CAP, L> {
+            fn drop(&mut self) {
+                // Only does something if `same_bucket` or a drop panicked:
+                // the unprocessed elements are moved next to the kept ones.
+                unsafe {
+                    let ptr = self.vec.as_mut_ptr();
+                    let tail_len = self.old_len - self.read;
+                    ptr::copy(ptr.add(self.read), ptr.add(self.write), tail_len);
+                    self.vec.set_len(self.write + tail_len);
+                }
             }
+        }
 
-            MaybeUninit::array_assume_init(arr)
+        let old_len = self.len();
+        if old_len <= 1 {
+            return;
+        }
+        unsafe {
+            self.set_len(0);
+        }
+        let mut gap = FillGapOnDrop {
+            read: 1,
+            write: 1,
+            old_len,
+            vec: self,
+        };
+        let ptr = gap.vec.as_mut_ptr();
+        unsafe {
+            while gap.read < old_len {
+                let read = ptr.add(gap.read);
+                let previous = ptr.add(gap.write - 1);
+                if same_bucket(&mut *read, &mut *previous) {
+                    // Move on before dropping, so the guard doesn't drop it again.
+                    gap.read += 1;
+                    ptr::drop_in_place(read);
+                } else {
+                    ptr::copy(read, ptr.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
         }
     }
+}
 
-    pub fn is_full(&self) -> bool {
-        self.length as usize == CAP
+impl<T: PartialEq, const CAP: usize, L: Length> ArrayVec<T, CAP, L> {
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b)
     }
 }
 
-impl<T, const CAP: usize> ArrayVec<T, CAP>
+impl<T: Clone, const CAP: usize, L: Length> ArrayVec<T, CAP, L> {
+    pub fn extend_from_slice(&mut self, s: &[T]) {
+        assert!(
+            CAP - self.len() >= s.len(),
+            "extending an ArrayVec beyond its capacity"
+        );
+        for e in s {
+            unsafe { self.push_unchecked(e.clone()) }
+        }
+    }
+
+    /// Appends all of `s`, or nothing if it doesn't fit.
+    pub fn try_extend_from_slice(&mut self, s: &[T]) -> Result<(), CapacityError> {
+        if CAP - self.len() < s.len() {
+            return Err(CapacityError::new(()));
+        }
+        self.extend_from_slice(s);
+        Ok(())
+    }
+}
+
+impl<T, const CAP: usize, L: Length> ArrayVec<T, CAP, L>
 where
     T: Copy,
 {
@@ -159,7 +588,7 @@ where
     }
 }
 
-impl<T, const CAP: usize> std::iter::FromIterator<T> for ArrayVec<T, CAP> {
+impl<T, const CAP: usize, L: Length> std::iter::FromIterator<T> for ArrayVec<T, CAP, L> {
     fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
         let mut vec = Self::new();
         for e in iter {
@@ -169,65 +598,394 @@ impl<T, const CAP: usize> std::iter::FromIterator<T> for ArrayVec<T, CAP> {
     }
 }
 
-pub struct ArrayVecIntoIter<T, const CAP: usize> {
-    array_vec: ArrayVec<T, CAP>,
+impl<T, const N: usize, const CAP: usize, L: Length> From<[T; N]> for ArrayVec<T, CAP, L> {
+    fn from(array: [T; N]) -> Self {
+        const { assert!(N <= CAP, "the array is longer than the capacity") };
+        let mut vec = Self::new();
+        for e in array {
+            unsafe { vec.push_unchecked(e) }
+        }
+        vec
+    }
+}
+
+/// Gives the vector back if it doesn't have exactly `N` elements.
+impl<T, const N: usize, const CAP: usize, L: Length> TryFrom<ArrayVec<T, CAP, L>> for [T; N] {
+    type Error = ArrayVec<T, CAP, L>;
+
+    fn try_from(vec: ArrayVec<T, CAP, L>) -> Result<Self, Self::Error> {
+        if vec.len() != N {
+            return Err(vec);
+        }
+        Ok(vec.to_array())
+    }
+}
+
+/// Owning iterator of an `ArrayVec`, whose elements not yet yielded are
+/// dropped with it.
+pub struct ArrayVecIntoIter<T, const CAP: usize, L: Length = u8> {
+    /// Has a length of 0, the elements are tracked by `current` and `end`.
+    array_vec: ArrayVec<T, CAP, L>,
     current: usize,
+    end: usize,
+}
+
+impl<T, const CAP: usize, L: Length> ArrayVecIntoIter<T, CAP, L> {
+    /// The elements not yet yielded.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.array_vec.as_ptr().add(self.current),
+                self.end - self.current,
+            )
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.array_vec.as_mut_ptr().add(self.current),
+                self.end - self.current,
+            )
+        }
+    }
 }
 
-impl<T, const CAP: usize> Iterator for ArrayVecIntoIter<T, CAP> {
+impl<T, const CAP: usize, L: Length> Iterator for ArrayVecIntoIter<T, CAP, L> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current < self.array_vec.len() {
-            let e;
-            unsafe {
-                e = self.array_vec.array[self.current].assume_init_read();
-                self.current += 1;
-            }
-            Some(e)
-        } else {
-            None
+        if self.current == self.end {
+            return None;
+        }
+        self.current += 1;
+        unsafe { Some(self.array_vec.as_ptr().add(self.current - 1).read()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.current;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const CAP: usize, L: Length> DoubleEndedIterator for ArrayVecIntoIter<T, CAP, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
         }
+        self.end -= 1;
+        unsafe { Some(self.array_vec.as_ptr().add(self.end).read()) }
+    }
+}
+
+impl<T, const CAP: usize, L: Length> ExactSizeIterator for ArrayVecIntoIter<T, CAP, L> {}
+
+impl<T, const CAP: usize, L: Length> std::iter::FusedIterator for ArrayVecIntoIter<T, CAP, L> {}
+
+impl<T: Clone, const CAP: usize, L: Length> Clone for ArrayVecIntoIter<T, CAP, L> {
+    fn clone(&self) -> Self {
+        let mut array_vec = ArrayVec::new();
+        array_vec.extend_from_slice(self.as_slice());
+        array_vec.into_iter()
+    }
+}
+
+impl<T: std::fmt::Debug, const CAP: usize, L: Length> std::fmt::Debug
+    for ArrayVecIntoIter<T, CAP, L>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ArrayVecIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T, const CAP: usize, L: Length> Drop for ArrayVecIntoIter<T, CAP, L> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
     }
 }
 
-impl<T, const CAP: usize> IntoIterator for ArrayVec<T, CAP> {
+impl<T, const CAP: usize, L: Length> IntoIterator for ArrayVec<T, CAP, L> {
     type Item = T;
-    type IntoIter = ArrayVecIntoIter<T, CAP>;
+    type IntoIter = ArrayVecIntoIter<T, CAP, L>;
 
-    fn into_iter(self) -> Self::IntoIter {
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.len();
+        unsafe { self.set_len(0) };
         ArrayVecIntoIter {
             array_vec: self,
             current: 0,
+            end,
         }
     }
 }
 
+impl<'a, T, const CAP: usize, L: Length> IntoIterator for &'a ArrayVec<T, CAP, L> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAP: usize, L: Length> IntoIterator for &'a mut ArrayVec<T, CAP, L> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         let result = 2 + 2;
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn wide_length() {
+        assert_eq!(std::mem::size_of::<ArrayVec<u8, 4>>(), 5);
+
+        let mut vec: ArrayVec<u16, 1000, u16> = (0..1000).collect();
+        assert!(vec.is_full());
+        let evens: Vec<u16> = vec.drain_filter(|e| *e % 2 == 0).collect();
+        assert_eq!(evens.len(), 500);
+        assert_eq!(vec.len(), 500);
+        assert_eq!(vec.pop(), Some(999));
+    }
+
+    #[test]
+    fn fallible_push() {
+        let mut vec = ArrayVec::<u32, 3>::try_from_iter([1, 2]).unwrap();
+        assert_eq!(vec.try_extend([3, 4, 5]).unwrap_err().element(), 4);
+        assert_eq!(*vec, [1, 2, 3]);
+        assert_eq!(vec.try_push(6), Err(CapacityError::new(6)));
+        assert!(ArrayVec::<u32, 2>::try_from_iter(0..3).is_err());
+
+        let mut vec = ArrayVec::<u32, 3>::from_slice(&[1]);
+        assert!(vec.try_extend_from_slice(&[2, 3, 4]).is_err());
+        assert_eq!(*vec, [1]);
+        vec.try_extend_from_slice(&[2, 3]).unwrap();
+        assert_eq!(*vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn vec_api() {
+        let mut vec = ArrayVec::<u32, 8>::from_slice(&[1, 2, 3]);
+        vec.insert(0, 0);
+        vec.insert(4, 4);
+        assert_eq!(*vec, [0, 1, 2, 3, 4]);
+        assert_eq!(vec.remove(1), 1);
+        assert_eq!(vec.swap_remove(0), 0);
+        assert_eq!(*vec, [4, 2, 3]);
+        vec.extend_from_slice(&[3, 3, 5, 4]);
+        vec.dedup();
+        assert_eq!(*vec, [4, 2, 3, 5, 4]);
+        vec.retain(|&e| e != 4);
+        assert_eq!(*vec, [2, 3, 5]);
+
+        let tail = vec.split_off(1);
+        assert_eq!((&*vec, &*tail), (&[2][..], &[3, 5][..]));
+        vec.extend_from_slice(&[6, 7, 8, 9]);
+        assert_eq!(vec.drain(1..3).rev().collect::<Vec<_>>(), [7, 6]);
+        assert_eq!(*vec, [2, 8, 9]);
+        vec.truncate(1);
+        assert_eq!(*vec, [2]);
+        vec.clear();
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn drain_drops_remaining() {
+        let rc = std::rc::Rc::new(());
+        let mut vec: ArrayVec<_, 4> = (0..4).map(|_| rc.clone()).collect();
+        let mut drain = vec.drain(..3);
+        drain.next();
+        drop(drain);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(std::rc::Rc::strong_count(&rc), 2);
+        vec.truncate(0);
+        assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn drops_elements() {
+        let rc = std::rc::Rc::new(());
+        let vec: ArrayVec<_, 4> = (0..4).map(|_| rc.clone()).collect();
+        let mut it = vec.clone().into_iter();
+        it.next();
+        drop(it);
+        let arr: [_; 2] = vec.clone().to_array();
+        assert_eq!(std::rc::Rc::strong_count(&rc), 7);
+        drop((vec, arr));
+        assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+
+        let mut copy = CopyArrayVec::<u8, 3>::from_slice(b"ab");
+        let before = copy;
+        copy.push(b'c');
+        assert_eq!((&**before, &**copy), (&b"ab"[..], &b"abc"[..]));
+    }
+
+    #[test]
+    fn into_iter() {
+        let vec = ArrayVec::<String, 4>::from_iter(["a", "b", "c"].map(String::from));
+        let mut sum = 0;
+        for s in &vec {
+            sum += s.len();
+        }
+        assert_eq!(sum, 3);
+
+        let mut it = vec.into_iter();
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.next_back().as_deref(), Some("c"));
+        let copy = it.clone();
+        assert_eq!(it.next().as_deref(), Some("a"));
+        assert_eq!(it.as_slice(), ["b"]);
+        assert_eq!(copy.rev().collect::<Vec<_>>(), ["b", "a"]);
+        assert_eq!(it.next().as_deref(), Some("b"));
+        assert_eq!((it.next(), it.next_back()), (None, None));
+    }
+
+    #[test]
+    fn array_conversions() {
+        static EMPTY: ArrayVec<u8, 4> = ArrayVec::new();
+        assert!(EMPTY.is_empty());
+
+        let vec: ArrayVec<String, 4> = arrayvec!["a".to_owned(), "b".to_owned()];
+        let vec = <[String; 3]>::try_from(vec).unwrap_err();
+        let [a, b] = <[String; 2]>::try_from(vec).unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("a", "b"));
+
+        let copy = CopyArrayVec::<u8, 3>::from([1, 2]);
+        assert_eq!(<[u8; 2]>::try_from(copy), Ok([1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds (len is 2)")]
+    fn drain_inclusive_max() {
+        let mut vec: ArrayVec<u8, 4> = [1, 2].into();
+        vec.drain(1..=usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "length 256 doesn't fit in a u8")]
+    fn length_too_big() {
+        u8::from_usize(256);
+    }
+}
+
+mod drain {
+    use std::ptr;
+
+    use crate::Length;
+
+    pub struct Drain<'a, T, const CAP: usize, L: Length = u8> {
+        pub(super) vec: &'a mut crate::ArrayVec<T, CAP, L>,
+        /// The index of the next element returned by `next`.
+        pub(super) idx: usize,
+        /// One past the index of the next element returned by `next_back`.
+        pub(super) end: usize,
+        /// Where the elements after the drained range start.
+        pub(super) tail_start: usize,
+        pub(super) tail_len: usize,
+    }
+
+    impl<T, const CAP: usize, L: Length> Iterator for Drain<'_, T, CAP, L> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            if self.idx == self.end {
+                return None;
+            }
+            self.idx += 1;
+            unsafe { Some(self.vec.as_ptr().add(self.idx - 1).read()) }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.end - self.idx;
+            (len, Some(len))
+        }
+    }
+
+    impl<T, const CAP: usize, L: Length> DoubleEndedIterator for Drain<'_, T, CAP, L> {
+        fn next_back(&mut self) -> Option<T> {
+            if self.idx == self.end {
+                return None;
+            }
+            self.end -= 1;
+            unsafe { Some(self.vec.as_ptr().add(self.end).read()) }
+        }
+    }
+
+    impl<T, const CAP: usize, L: Length> ExactSizeIterator for Drain<'_, T, CAP, L> {}
+
+    impl<T, const CAP: usize, L: Length> std::iter::FusedIterator for Drain<'_, T, CAP, L> {}
+
+    impl<T, const CAP: usize, L: Length> Drop for Drain<'_, T, CAP, L> {
+        fn drop(&mut self) {
+            struct MoveTailOnDrop<'a, 'b, T, const CAP: usize, L: Length> {
+                drain: &'b mut Drain<'a, T, CAP, L>,
+            }
+
+            impl<T, const CAP: usize, L: Length> Drop for MoveTailOnDrop<'_, '_, T, CAP, L> {
+                fn drop(&mut self) {
+                    unsafe {
+                        let vec = &mut *self.drain.vec;
+                        let start = vec.len();
+                        let ptr = vec.as_mut_ptr();
+                        ptr::copy(
+                            ptr.add(self.drain.tail_start),
+                            ptr.add(start),
+                            self.drain.tail_len,
+                        );
+                        vec.set_len(start + self.drain.tail_len);
+                    }
+                }
+            }
+
+            let guard = MoveTailOnDrop { drain: self };
+            // Drop the elements that weren't yielded. The tail is moved back
+            // by the guard even if one of these drops panics.
+            let remaining = guard.drain.end - guard.drain.idx;
+            let idx = guard.drain.idx;
+            guard.drain.idx = guard.drain.end;
+            unsafe {
+                let ptr = guard.drain.vec.as_mut_ptr().add(idx);
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, remaining));
+            }
+        }
+    }
 }
 
 mod drain_filter {
     use std::ptr;
     use std::slice;
 
+    use crate::Length;
+
     #[derive(Debug)]
-    pub struct DrainFilter<'a, T, F, const CAP: usize>
+    pub struct DrainFilter<'a, T, F, const CAP: usize, L: Length = u8>
     where
         F: FnMut(&mut T) -> bool,
     {
-        pub(super) vec: &'a mut crate::ArrayVec<T, CAP>,
+        pub(super) vec: &'a mut crate::ArrayVec<T, CAP, L>,
         /// The index of the item that will be inspected by the next call to `next`.
-        pub(super) idx: u8,
+        pub(super) idx: usize,
         /// The number of items that have been drained (removed) thus far.
-        pub(super) del: u8,
+        pub(super) del: usize,
         /// The original length of `vec` prior to draining.
-        pub(super) old_len: u8,
+        pub(super) old_len: usize,
         /// The filter test predicate.
         pub(super) pred: F,
         /// A flag that indicates a panic has occurred in the filter test predicate.
@@ -238,7 +996,7 @@ mod drain_filter {
         pub(super) panic_flag: bool,
     }
 
-    impl<T, F, const CAP: usize> Iterator for DrainFilter<'_, T, F, CAP>
+    impl<T, F, const CAP: usize, L: Length> Iterator for DrainFilter<'_, T, F, CAP, L>
     where
         F: FnMut(&mut T) -> bool,
     {
@@ -248,9 +1006,9 @@ mod drain_filter {
             unsafe {
                 while self.idx < self.old_len {
                     let i = self.idx;
-                    let v = slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len as usize);
+                    let v = slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len);
                     self.panic_flag = true;
-                    let drained = (self.pred)(&mut v[i as usize]);
+                    let drained = (self.pred)(&mut v[i]);
                     self.panic_flag = false;
                     // Update the index *after* the predicate is called. If the index
                     // is updated prior and the predicate panics, the element at this
@@ -258,11 +1016,11 @@ mod drain_filter {
                     self.idx += 1;
                     if drained {
                         self.del += 1;
-                        return Some(ptr::read(&v[i as usize]));
+                        return Some(ptr::read(&v[i]));
                     } else if self.del > 0 {
                         let del = self.del;
-                        let src: *const T = &v[i as usize];
-                        let dst: *mut T = &mut v[i as usize - del as usize];
+                        let src: *const T = &v[i];
+                        let dst: *mut T = &mut v[i - del];
                         ptr::copy_nonoverlapping(src, dst, 1);
                     }
                 }
@@ -271,23 +1029,23 @@ mod drain_filter {
         }
 
         fn size_hint(&self) -> (usize, Option<usize>) {
-            (0, Some(self.old_len as usize - self.idx as usize))
+            (0, Some(self.old_len - self.idx))
         }
     }
 
-    impl<T, F, const CAP: usize> Drop for DrainFilter<'_, T, F, CAP>
+    impl<T, F, const CAP: usize, L: Length> Drop for DrainFilter<'_, T, F, CAP, L>
     where
         F: FnMut(&mut T) -> bool,
     {
         fn drop(&mut self) {
-            struct BackshiftOnDrop<'a, 'b, T, F, const CAP: usize>
+            struct BackshiftOnDrop<'a, 'b, T, F, const CAP: usize, L: Length>
             where
                 F: FnMut(&mut T) -> bool,
             {
-                drain: &'b mut DrainFilter<'a, T, F, CAP>,
+                drain: &'b mut DrainFilter<'a, T, F, CAP, L>,
             }
 
-            impl<'a, 'b, T, F, const CAP: usize> Drop for BackshiftOnDrop<'a, 'b, T, F, CAP>
+            impl<'a, 'b, T, F, const CAP: usize, L: Length> Drop for BackshiftOnDrop<'a, 'b, T, F, CAP, L>
             where
                 F: FnMut(&mut T) -> bool,
             {
@@ -301,10 +1059,10 @@ mod drain_filter {
                             // is required to prevent a double-drop of the last successfully
                             // drained item prior to a panic in the predicate.
                             let ptr = self.drain.vec.as_mut_ptr();
-                            let src = ptr.add(self.drain.idx as usize);
-                            let dst = src.sub(self.drain.del as usize);
+                            let src = ptr.add(self.drain.idx);
+                            let dst = src.sub(self.drain.del);
                             let tail_len = self.drain.old_len - self.drain.idx;
-                            src.copy_to(dst, tail_len as usize);
+                            src.copy_to(dst, tail_len);
                         }
                         self.drain.vec.set_len(self.drain.old_len - self.drain.del);
                     }
@@ -323,55 +1081,189 @@ mod drain_filter {
     }
 }
 
-impl<T, const CAP: usize> Default for ArrayVec<T, CAP> {
+impl<T, const CAP: usize, L: Length> Default for ArrayVec<T, CAP, L> {
     fn default() -> Self {
         ArrayVec::new()
     }
 }
 
-impl<T: std::hash::Hash, const CAP: usize> std::hash::Hash for ArrayVec<T, CAP> {
+impl<T: std::hash::Hash, const CAP: usize, L: Length> std::hash::Hash for ArrayVec<T, CAP, L> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.deref().hash(state)
     }
 }
 
-impl<T: std::cmp::PartialOrd, const CAP: usize> std::cmp::PartialOrd for ArrayVec<T, CAP> {
+impl<T: std::cmp::PartialOrd, const CAP: usize, L: Length> std::cmp::PartialOrd
+    for ArrayVec<T, CAP, L>
+{
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.deref().partial_cmp(other.deref())
     }
 }
 
-impl<T: std::cmp::Ord, const CAP: usize> std::cmp::Ord for ArrayVec<T, CAP> {
+impl<T: std::cmp::Ord, const CAP: usize, L: Length> std::cmp::Ord for ArrayVec<T, CAP, L> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.deref().cmp(other.deref())
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-pub struct ArrayStr<const CAP: usize>(ArrayVec<u8, CAP>);
+/// An `ArrayVec` of `Copy` elements, which is `Copy` itself. It dereferences
+/// to the `ArrayVec` for everything else.
+#[repr(C)]
+pub struct CopyArrayVec<T: Copy, const CAP: usize, L: Length = u8> {
+    length: L,
+    array: [MaybeUninit<T>; CAP],
+}
+
+impl<T: Copy, const CAP: usize, L: Length> CopyArrayVec<T, CAP, L> {
+    pub const fn new() -> Self {
+        const { assert!(CAP <= L::MAX, "capacity too big for the length type") };
+        Self {
+            length: L::ZERO,
+            array: [const { MaybeUninit::uninit() }; CAP],
+        }
+    }
+
+    pub fn from_slice(s: &[T]) -> Self {
+        ArrayVec::from_slice(s).into()
+    }
+
+    pub fn into_array_vec(self) -> ArrayVec<T, CAP, L> {
+        ArrayVec {
+            length: self.length,
+            array: self.array,
+        }
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: Length> From<ArrayVec<T, CAP, L>> for CopyArrayVec<T, CAP, L> {
+    fn from(vec: ArrayVec<T, CAP, L>) -> Self {
+        // `T: Copy` has nothing to drop, so skipping the drop of `vec` is fine.
+        let vec = std::mem::ManuallyDrop::new(vec);
+        Self {
+            length: vec.length,
+            array: unsafe { ptr::read(&vec.array) },
+        }
+    }
+}
 
-impl<const CAP: usize> std::str::FromStr for ArrayStr<CAP> {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(ArrayVec::from_slice(s.as_bytes())))
+impl<T: Copy, const N: usize, const CAP: usize, L: Length> From<[T; N]>
+    for CopyArrayVec<T, CAP, L>
+{
+    fn from(array: [T; N]) -> Self {
+        ArrayVec::from(array).into()
     }
 }
 
-impl<const CAP: usize> std::ops::Deref for ArrayStr<CAP> {
-    type Target = str;
+impl<T: Copy, const N: usize, const CAP: usize, L: Length> TryFrom<CopyArrayVec<T, CAP, L>>
+    for [T; N]
+{
+    type Error = CopyArrayVec<T, CAP, L>;
+
+    fn try_from(vec: CopyArrayVec<T, CAP, L>) -> Result<Self, Self::Error> {
+        vec.into_array_vec().try_into().map_err(CopyArrayVec::from)
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: Length> std::ops::Deref for CopyArrayVec<T, CAP, L> {
+    type Target = ArrayVec<T, CAP, L>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::str::from_utf8_unchecked(&self.0) }
+        // Both types are `repr(C)` with the same fields.
+        unsafe { &*(self as *const Self).cast::<ArrayVec<T, CAP, L>>() }
     }
 }
 
-impl<const CAP: usize> std::fmt::Debug for ArrayStr<CAP> {
+impl<T: Copy, const CAP: usize, L: Length> std::ops::DerefMut for CopyArrayVec<T, CAP, L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Dropping an `ArrayVec` of `Copy` elements does nothing, so it can't
+        // matter if it is moved out of this reference.
+        unsafe { &mut *(self as *mut Self).cast::<ArrayVec<T, CAP, L>>() }
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: Length> Clone for CopyArrayVec<T, CAP, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: Length> Copy for CopyArrayVec<T, CAP, L> {}
+
+impl<T: Copy, const CAP: usize, L: Length> Default for CopyArrayVec<T, CAP, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + std::fmt::Debug, const CAP: usize, L: Length> std::fmt::Debug
+    for CopyArrayVec<T, CAP, L>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.deref().fmt(f)
     }
 }
 
-impl<const CAP: usize> std::hash::Hash for ArrayStr<CAP> {
+impl<T: Copy + PartialEq, const CAP: usize, L: Length> PartialEq for CopyArrayVec<T, CAP, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T: Copy + Eq, const CAP: usize, L: Length> Eq for CopyArrayVec<T, CAP, L> {}
+
+impl<T: Copy + std::hash::Hash, const CAP: usize, L: Length> std::hash::Hash
+    for CopyArrayVec<T, CAP, L>
+{
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.deref().hash(state)
     }
 }
+
+impl<T: Copy + std::cmp::PartialOrd, const CAP: usize, L: Length> std::cmp::PartialOrd
+    for CopyArrayVec<T, CAP, L>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<T: Copy + std::cmp::Ord, const CAP: usize, L: Length> std::cmp::Ord
+    for CopyArrayVec<T, CAP, L>
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: Length> std::iter::FromIterator<T> for CopyArrayVec<T, CAP, L> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        ArrayVec::from_iter(iter).into()
+    }
+}
+
+impl<'a, T: Copy, const CAP: usize, L: Length> IntoIterator for &'a CopyArrayVec<T, CAP, L> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Copy, const CAP: usize, L: Length> IntoIterator for &'a mut CopyArrayVec<T, CAP, L> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: Length> IntoIterator for CopyArrayVec<T, CAP, L> {
+    type Item = T;
+    type IntoIter = ArrayVecIntoIter<T, CAP, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_array_vec().into_iter()
+    }
+}
@@ -0,0 +1,101 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{ArrayStr, ArrayVec, CopyArrayVec, Length};
+
+impl<T: Serialize, const CAP: usize, L: Length> Serialize for ArrayVec<T, CAP, L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const CAP: usize, L: Length> Deserialize<'de>
+    for ArrayVec<T, CAP, L>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArrayVecVisitor<T, const CAP: usize, L>(PhantomData<(T, L)>);
+
+        impl<'de, T: Deserialize<'de>, const CAP: usize, L: Length> Visitor<'de>
+            for ArrayVecVisitor<T, CAP, L>
+        {
+            type Value = ArrayVec<T, CAP, L>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of at most {} elements", CAP)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut vec = ArrayVec::new();
+                while let Some(e) = seq.next_element()? {
+                    if vec.try_push(e).is_err() {
+                        let len = CAP + 1 + seq.size_hint().unwrap_or(0);
+                        return Err(de::Error::invalid_length(len, &self));
+                    }
+                }
+                Ok(vec)
+            }
+        }
+
+        deserializer.deserialize_seq(ArrayVecVisitor(PhantomData))
+    }
+}
+
+impl<T: Copy + Serialize, const CAP: usize, L: Length> Serialize for CopyArrayVec<T, CAP, L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<'de, T: Copy + Deserialize<'de>, const CAP: usize, L: Length> Deserialize<'de>
+    for CopyArrayVec<T, CAP, L>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ArrayVec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<const CAP: usize, L: Length> Serialize for ArrayStr<CAP, L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de, const CAP: usize, L: Length> Deserialize<'de> for ArrayStr<CAP, L> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArrayStrVisitor<const CAP: usize, L>(PhantomData<L>);
+
+        impl<const CAP: usize, L: Length> Visitor<'_> for ArrayStrVisitor<CAP, L> {
+            type Value = ArrayStr<CAP, L>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a string of at most {} bytes", CAP)
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                ArrayStr::try_from(v).map_err(|e| E::invalid_length(e.needed(), &self))
+            }
+        }
+
+        deserializer.deserialize_str(ArrayStrVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArrayStr, ArrayVec, CopyArrayVec};
+
+    #[test]
+    fn checks_capacity() {
+        let vec: ArrayVec<u8, 3> = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(serde_json::to_string(&vec).unwrap(), "[1,2,3]");
+        assert!(serde_json::from_str::<CopyArrayVec<u8, 2>>("[1,2,3]").is_err());
+
+        let s: ArrayStr<2> = serde_json::from_str(r#""ab""#).unwrap();
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#""ab""#);
+        assert!(serde_json::from_str::<ArrayStr<2>>(r#""abc""#).is_err());
+    }
+}
@@ -0,0 +1,46 @@
+use solution::{Answer, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    solution::lines(input)
+        .map(|line| line.parse(line.text.trim(), "a number"))
+        .collect()
+}
+
+fn number_of_increases(v: &[i32]) -> usize {
+    v.windows(2)
+        .map(|window| if window[1] > window[0] { 1 } else { 0 })
+        .sum()
+}
+
+fn rolling_sum_3(v: &[i32]) -> Vec<i32> {
+    v.windows(3).map(|window| window.iter().sum()).collect()
+}
+
+fn step_1(input: &[i32]) -> usize {
+    number_of_increases(input)
+}
+
+fn step_2(input: &[i32]) -> usize {
+    let rolled_3 = rolling_sum_3(input);
+    number_of_increases(&rolled_3)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
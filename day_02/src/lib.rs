@@ -0,0 +1,84 @@
+use solution::{Answer, ParseError, Solution};
+
+pub enum Command {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    solution::lines(input)
+        .map(|line| {
+            let (command, length) = line.split_once(line.text.trim(), " ")?;
+            let length = line.parse(length, "a length")?;
+            Ok(match command {
+                "forward" => Command::Forward(length),
+                "up" => Command::Up(length),
+                "down" => Command::Down(length),
+                _ => return Err(line.error(command, "'forward', 'up' or 'down'")),
+            })
+        })
+        .collect()
+}
+
+fn apply_commands(commands: &[Command]) -> i32 {
+    let mut horizontal_pos = 0;
+    let mut vertical_pos = 0;
+
+    for command in commands {
+        match command {
+            Command::Forward(length) => horizontal_pos += length,
+            Command::Up(length) => vertical_pos -= length,
+            Command::Down(length) => vertical_pos += length,
+        }
+    }
+
+    horizontal_pos * vertical_pos
+}
+
+fn apply_commands_with_aim(commands: &[Command]) -> i32 {
+    let mut horizontal_pos = 0;
+    let mut vertical_pos = 0;
+    let mut aim = 0;
+
+    for command in commands {
+        match command {
+            Command::Forward(length) => {
+                horizontal_pos += length;
+                vertical_pos += length * aim
+            }
+            Command::Up(length) => aim -= length,
+            Command::Down(length) => aim += length,
+        }
+    }
+
+    horizontal_pos * vertical_pos
+}
+
+fn step_1(commands: &[Command]) -> i32 {
+    apply_commands(commands)
+}
+
+fn step_2(commands: &[Command]) -> i32 {
+    apply_commands_with_aim(commands)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
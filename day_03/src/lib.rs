@@ -0,0 +1,106 @@
+use arrayvec::BitSet;
+use solution::{Answer, ParseError, Solution};
+
+/// A binary number, as the set of its bits that are 1.
+pub type Number = BitSet<16, u16>;
+
+fn parse_input(input: &str) -> Result<(Vec<Number>, usize), ParseError> {
+    let mut lines = solution::lines(input);
+    let first = lines.expect("a binary number")?;
+    let number_of_digits = first.text.trim().len();
+    if number_of_digits > 16 {
+        return Err(first.error(first.text.trim(), "at most 16 digits"));
+    }
+    let numbers = std::iter::once(first)
+        .chain(lines)
+        .map(|line| {
+            let digits = line.text.trim();
+            if digits.len() != number_of_digits {
+                return Err(line.error(digits, format!("{} digits", number_of_digits)));
+            }
+            let mut n = Number::new();
+            for (i, digit) in digits.char_indices() {
+                match digit {
+                    '0' => {}
+                    '1' => {
+                        n.insert(number_of_digits - 1 - i);
+                    }
+                    _ => return Err(line.error(&digits[i..i + digit.len_utf8()], "'0' or '1'")),
+                }
+            }
+            Ok(n)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((numbers, number_of_digits))
+}
+
+/// Whether the bit at `power_of_2` is more often 1, `None` on a tie.
+fn most_frequent_digit(power_of_2: usize, numbers: &[Number]) -> Option<bool> {
+    let count_of_0 = numbers.iter().filter(|n| !n.contains(power_of_2)).count();
+    use std::cmp::Ordering::*;
+    match count_of_0.cmp(&(numbers.len() / 2)) {
+        Equal => None,
+        Greater => Some(false),
+        Less => Some(true),
+    }
+}
+
+fn first_step(numbers: &[Number], num_of_digits: usize) -> u64 {
+    let gamma_rate: Number = (0..num_of_digits)
+        .filter(|&power_of_2| most_frequent_digit(power_of_2, numbers).unwrap_or(true))
+        .collect();
+    let epsilon_rate = (0..num_of_digits).collect::<Number>() - gamma_rate;
+
+    epsilon_rate.bits() as u64 * gamma_rate.bits() as u64
+}
+
+fn second_step(numbers: &[Number], num_of_digits: usize) -> u64 {
+    let mut most_frequent_set = numbers.to_owned();
+    for power_of_2 in (0..num_of_digits).rev() {
+        if most_frequent_set.len() == 1 {
+            break;
+        }
+        let most_frequent = most_frequent_digit(power_of_2, &most_frequent_set).unwrap_or(true);
+        most_frequent_set.retain(|n| n.contains(power_of_2) == most_frequent);
+    }
+
+    let mut least_frequent_set = numbers.to_owned();
+    for power_of_2 in (0..num_of_digits).rev() {
+        if least_frequent_set.len() == 1 {
+            break;
+        }
+        let least_frequent = !most_frequent_digit(power_of_2, &least_frequent_set).unwrap_or(true);
+        least_frequent_set.retain(|n| n.contains(power_of_2) == least_frequent);
+    }
+    let oxy_gen_rating = most_frequent_set[0];
+    let c02_gen_rating = least_frequent_set[0];
+    oxy_gen_rating.bits() as u64 * c02_gen_rating.bits() as u64
+}
+
+fn step_1(input: &(Vec<Number>, usize)) -> u64 {
+    first_step(&input.0, input.1)
+}
+
+fn step_2(input: &(Vec<Number>, usize)) -> u64 {
+    second_step(&input.0, input.1)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = (Vec<Number>, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
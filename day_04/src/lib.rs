@@ -0,0 +1,155 @@
+use arrayvec::ArrayMap;
+use solution::{Answer, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<(Vec<u8>, Vec<Grid>), ParseError> {
+    let mut lines = solution::lines(input);
+    let draws_line = lines.expect("the draws")?;
+    let draws: Vec<u8> = draws_line
+        .text
+        .trim()
+        .split(',')
+        .map(|n| draws_line.parse(n, "a number"))
+        .collect::<Result<_, _>>()?;
+    let blank = lines.expect("an empty line")?;
+    if !blank.text.trim().is_empty() {
+        return Err(blank.error(blank.text, "an empty line"));
+    }
+    let mut grids = Vec::new();
+    let mut current_grid = Grid::new();
+    let mut j = 0;
+    for line in lines {
+        if line.text.trim() == "" {
+            grids.push(current_grid);
+            current_grid = Grid::new();
+            j = 0;
+            continue;
+        }
+        if j == 5 {
+            return Err(line.error(line.text, "an empty line after 5 rows"));
+        }
+        for (i, number) in line
+            .text
+            .split(' ')
+            .filter(|c| !c.trim().is_empty())
+            .enumerate()
+        {
+            if i == 5 {
+                return Err(line.error(number, "5 numbers per row"));
+            }
+            current_grid
+                .numbers
+                .insert(line.parse(number.trim(), "a number")?, (i as u8, j, false));
+        }
+        j += 1;
+    }
+    grids.push(current_grid);
+    Ok((draws, grids))
+}
+
+#[derive(Clone, Debug)]
+pub struct Grid {
+    numbers: ArrayMap<u8, (u8, u8, bool), 25>,
+    count_row: [u8; 5],
+    count_col: [u8; 5],
+    win: bool,
+}
+
+impl Grid {
+    fn new() -> Self {
+        Self {
+            numbers: ArrayMap::new(),
+            count_row: [0; 5],
+            count_col: [0; 5],
+            win: false,
+        }
+    }
+
+    fn update_draw(&mut self, draw: u8) -> bool {
+        let (i, j, drawn) = match self.numbers.get_mut(&draw) {
+            None => return false,
+            Some(n) => n,
+        };
+        *drawn = true;
+        self.count_row[*j as usize] += 1;
+        self.count_col[*i as usize] += 1;
+
+        let (i, j) = (*i, *j);
+        self.win = self.has_won(i, j);
+        self.win
+    }
+
+    fn has_won(&self, i: u8, j: u8) -> bool {
+        self.count_row[j as usize] == 5 || self.count_col[i as usize] == 5
+    }
+
+    fn points_left(&self) -> u64 {
+        self.numbers
+            .iter()
+            .filter(|(_, (_, _, drawn))| !drawn)
+            .map(|(v, _)| *v as u64)
+            .sum::<u64>()
+    }
+}
+
+fn first_step(draws: &[u8], mut grids: Vec<Grid>) -> u64 {
+    let mut last_drawn = 0;
+    let mut winning_grid = 0;
+    'win: for draw in draws.iter() {
+        for (i, grid) in grids.iter_mut().enumerate() {
+            let has_won = grid.update_draw(*draw);
+            if has_won {
+                winning_grid = i;
+                last_drawn = *draw;
+                break 'win;
+            }
+        }
+    }
+    (last_drawn as u64) * grids[winning_grid].points_left()
+}
+
+fn second_step(draws: &[u8], grids: Vec<Grid>) -> u64 {
+    let mut not_won_grids: Vec<_> = grids;
+    let mut last_draw = 0;
+    let mut last_winner = None;
+    for draw in draws.iter() {
+        if let Some(g) = not_won_grids
+            .extract_if(.., |grid| grid.update_draw(*draw))
+            .last()
+        {
+            if not_won_grids.is_empty() {
+                last_winner = Some(g);
+                last_draw = *draw;
+                break;
+            }
+        }
+    }
+    last_winner.unwrap().points_left() * last_draw as u64
+}
+
+fn step_1((draws, grids): &(Vec<u8>, Vec<Grid>)) -> u64 {
+    first_step(draws, grids.clone())
+}
+
+fn step_2((draws, grids): &(Vec<u8>, Vec<Grid>)) -> u64 {
+    second_step(draws, grids.clone())
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = (Vec<u8>, Vec<Grid>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
@@ -0,0 +1,105 @@
+use solution::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    fn parse_point<'a>(line: &solution::Line<'a>, point: &'a str) -> Result<Point, ParseError> {
+        let (x, y) = line.split_once(point.trim(), ",")?;
+        Ok(Point {
+            x: line.parse(x, "a coordinate")?,
+            y: line.parse(y, "a coordinate")?,
+        })
+    }
+    solution::lines(input)
+        .map(|l| {
+            let (start, end) = l.split_once(l.text, "->")?;
+            Ok(Line {
+                start: parse_point(&l, start)?,
+                end: parse_point(&l, end)?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    x: i16,
+    y: i16,
+}
+
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+fn coordinate_delta(start: i16, end: i16) -> i16 {
+    use std::cmp::Ordering::*;
+    match start.cmp(&end) {
+        Equal => 0,
+        Less => 1,
+        Greater => -1,
+    }
+}
+
+impl Line {
+    fn point_range<'a>(&'a self) -> impl Iterator<Item = Point> + 'a {
+        let mut cur_pos = self.start;
+        let mut end = false;
+        std::iter::from_fn(move || {
+            if cur_pos == self.end {
+                if end {
+                    return None;
+                }
+                end = true;
+                return Some(cur_pos);
+            }
+            let pos = cur_pos;
+            cur_pos.x += coordinate_delta(self.start.x, self.end.x);
+            cur_pos.y += coordinate_delta(self.start.y, self.end.y);
+            Some(pos)
+        })
+    }
+}
+
+fn step_1(lines: &[Line]) -> usize {
+    let mut point_counts = HashMap::new();
+    for line in lines {
+        if line.start.x != line.end.x && line.start.y != line.end.y {
+            continue;
+        }
+
+        for p in line.point_range() {
+            *point_counts.entry(p).or_insert(0) += 1;
+        }
+    }
+    point_counts.values().filter(|&&v| v > 1).count()
+}
+
+fn step_2(lines: &[Line]) -> usize {
+    let mut point_counts = HashMap::new();
+    for line in lines {
+        for p in line.point_range() {
+            *point_counts.entry(p).or_insert(0) += 1;
+        }
+    }
+    point_counts.values().filter(|&&v| v > 1).count()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
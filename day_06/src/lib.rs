@@ -0,0 +1,56 @@
+use arrayvec::ArrayDeque;
+use solution::{Answer, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    let line = solution::lines(input).expect("a list of numbers")?;
+    line.text
+        .split(',')
+        .map(|n| match line.parse(n, "a timer between 0 and 8")? {
+            timer @ 0..=8 => Ok(timer),
+            _ => Err(line.error(n, "a timer between 0 and 8")),
+        })
+        .collect()
+}
+
+fn reproduce(start_days: &[u8], days_to_live: u32) -> u64 {
+    // Number of fishes by days left before they reproduce.
+    let mut bins: ArrayDeque<u64, 9> = [0; 9].into_iter().collect();
+    for day in start_days {
+        bins[*day as usize] += 1;
+    }
+
+    for _ in 0..days_to_live {
+        bins.rotate_left(1);
+        bins[6] += bins[8];
+    }
+
+    bins.iter().sum()
+}
+
+fn step_1(start_days: &[u8]) -> u64 {
+    reproduce(start_days, 80)
+}
+
+fn step_2(start_days: &[u8]) -> u64 {
+    reproduce(start_days, 256)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
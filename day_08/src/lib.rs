@@ -0,0 +1,171 @@
+use arrayvec::{ArrayVec, BitSet};
+use solution::{Answer, Line, ParseError, Solution};
+
+/// The set of lit segments, `a` to `g`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Digit(BitSet<7>);
+
+impl Digit {
+    fn from_char(c: char) -> Option<Self> {
+        let segment = match c {
+            'a'..='g' => c as usize - 'a' as usize,
+            _ => return None,
+        };
+        Some(Self([segment].into_iter().collect()))
+    }
+
+    fn powered_segments(&self) -> u8 {
+        self.0.len() as u8
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    fn contains(&self, other: &Self) -> bool {
+        other.0.is_subset(&self.0)
+    }
+
+    fn is_unique(&self) -> bool {
+        self.powered_segments() == 2
+            || self.powered_segments() == 4
+            || self.powered_segments() == 3
+            || self.powered_segments() == 7
+    }
+}
+
+pub struct Sequence {
+    digits: [Digit; 10],
+    solution: [Digit; 4],
+}
+
+fn parse_digit<'a>(line: &Line<'a>, s: &'a str) -> Result<Digit, ParseError> {
+    let mut d = Digit(BitSet::new());
+    for (i, c) in s.char_indices() {
+        let segment = Digit::from_char(c)
+            .ok_or_else(|| line.error(&s[i..i + c.len_utf8()], "a segment between 'a' and 'g'"))?;
+        d.0 ^= segment.0;
+    }
+    Ok(d)
+}
+
+fn parse_digits<'a, const N: usize>(
+    line: &Line<'a>,
+    part: &'a str,
+) -> Result<[Digit; N], ParseError> {
+    let mut digits = ArrayVec::<_, N>::new();
+    for s in part.split_ascii_whitespace() {
+        digits
+            .try_push(parse_digit(line, s)?)
+            .map_err(|_| line.error(s, format!("only {} digits", N)))?;
+    }
+    digits
+        .try_into()
+        .map_err(|_| line.missing(part, format!("{} digits", N)))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Sequence>, ParseError> {
+    solution::lines(input)
+        .map(|l| {
+            let (digits, solution) = l.split_once(l.text, "|")?;
+            Ok(Sequence {
+                digits: parse_digits(&l, digits)?,
+                solution: parse_digits(&l, solution)?,
+            })
+        })
+        .collect()
+}
+
+fn step_1(input: &[Sequence]) -> usize {
+    input
+        .iter()
+        .flat_map(|s| s.solution.iter())
+        .filter(|&d| d.is_unique())
+        .count()
+}
+
+fn solve_mapping(s: &Sequence) -> [Digit; 10] {
+    let mut digits: ArrayVec<Digit, 10> = s.digits.iter().cloned().collect();
+    let mut mapping: [Option<Digit>; 10] = Default::default();
+
+    mapping[1] = digits.drain_filter(|d| d.powered_segments() == 2).last();
+    mapping[4] = digits.drain_filter(|d| d.powered_segments() == 4).last();
+    mapping[7] = digits.drain_filter(|d| d.powered_segments() == 3).last();
+    mapping[8] = digits.drain_filter(|d| d.powered_segments() == 7).last();
+
+    mapping[3] = digits
+        .drain_filter(|d| {
+            d.powered_segments() == 5 && d.difference(&mapping[7].unwrap()).powered_segments() == 2
+        })
+        .last();
+
+    let b = mapping[4].unwrap().difference(&mapping[3].unwrap());
+
+    mapping[2] = digits
+        .drain_filter(|d| d.powered_segments() == 5 && !d.contains(&b))
+        .last();
+
+    mapping[5] = digits.drain_filter(|d| d.powered_segments() == 5).last();
+
+    let e = mapping[2].unwrap().difference(&mapping[3].unwrap());
+    let d = mapping[4]
+        .unwrap()
+        .difference(&mapping[1].unwrap())
+        .difference(&b);
+
+    mapping[0] = digits
+        .drain_filter(|di| di.powered_segments() == 6 && !di.contains(&d))
+        .last();
+
+    mapping[6] = digits
+        .drain_filter(|di| di.powered_segments() == 6 && di.contains(&e))
+        .last();
+
+    mapping[9] = digits.pop();
+
+    mapping.map(Option::unwrap)
+}
+
+fn apply_mapping_to_solution(seq: &Sequence, mapping: &[Digit; 10]) -> u64 {
+    let mut sol = 0;
+    for d in seq.solution.iter() {
+        sol *= 10;
+        let n = mapping
+            .iter()
+            .enumerate()
+            .find(|(_, mapped_d)| *mapped_d == d)
+            .unwrap()
+            .0;
+        sol += n as u64;
+    }
+    sol
+}
+
+fn step_2(input: &[Sequence]) -> u64 {
+    let mut sum = 0;
+    for seq in input {
+        let mapping = solve_mapping(seq);
+        sum += apply_mapping_to_solution(seq, &mapping);
+    }
+    sum
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
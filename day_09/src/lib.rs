@@ -0,0 +1,82 @@
+use matrix::Matrix;
+use solution::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+pub type Grid = Matrix<u8>;
+
+fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    Ok(Matrix::from_digit_str(input)?)
+}
+
+fn step_1(g: &Grid) -> u64 {
+    let mut local_minimas = 0;
+    for (i, j) in g.iter_coords() {
+        // dbg!(g.dims(), i, j);
+        let mut greater_neighbors = 0;
+        let mut neighbors_count = 0;
+        for (_, neigh) in g.neighbors(i, j) {
+            neighbors_count += 1;
+            if neigh > g.get(i, j).unwrap() {
+                greater_neighbors += 1;
+            }
+        }
+        if greater_neighbors == neighbors_count {
+            local_minimas = local_minimas + 1 + (*g.get(i, j).unwrap() as u64);
+        }
+    }
+    local_minimas
+}
+
+fn step_2(g: &Grid) -> u64 {
+    let mut biggest_bassins = arrayvec::ArrayVec::<u64, 4>::new();
+    for (i, j) in g.iter_coords() {
+        let value = *g.get(i, j).unwrap();
+
+        let mut greater_neighbors = 0;
+        let mut neighbors_count = 0;
+        for (_, neigh) in g.neighbors(i, j) {
+            neighbors_count += 1;
+            if *neigh > value {
+                greater_neighbors += 1;
+            }
+        }
+        if greater_neighbors != neighbors_count {
+            continue;
+        }
+        let mut bassin_boundaries = vec![((i, j), value)];
+        let mut bassin_visited = HashSet::new();
+        while let Some(next) = bassin_boundaries.pop() {
+            bassin_visited.insert(next.0);
+            for ((ni, nj), &nval) in g.neighbors(next.0 .0, next.0 .1) {
+                if nval != 9 && nval > next.1 && !bassin_visited.contains(&(ni, nj)) {
+                    bassin_boundaries.push(((ni, nj), nval));
+                }
+            }
+        }
+        let size = bassin_visited.len() as u64;
+        let rank = biggest_bassins.partition_point(|&b| b >= size);
+        biggest_bassins.insert(rank, size);
+        biggest_bassins.truncate(3);
+    }
+    biggest_bassins.iter().copied().product()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
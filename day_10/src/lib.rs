@@ -0,0 +1,129 @@
+use arrayvec::ArrayMap;
+use solution::{Answer, ParseError, Solution};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum BracketType {
+    Straight,
+    Parenthesis,
+    Curly,
+    Angle,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Orientation {
+    Open,
+    Close,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<(BracketType, Orientation)>>, ParseError> {
+    use BracketType::*;
+    use Orientation::*;
+    solution::lines(input)
+        .map(|l| {
+            let brackets = l.text.trim();
+            brackets
+                .char_indices()
+                .map(|(i, c)| {
+                    Ok(match c {
+                        '(' => (Parenthesis, Open),
+                        ')' => (Parenthesis, Close),
+                        '[' => (Straight, Open),
+                        ']' => (Straight, Close),
+                        '<' => (Angle, Open),
+                        '>' => (Angle, Close),
+                        '{' => (Curly, Open),
+                        '}' => (Curly, Close),
+                        _ => return Err(l.error(&brackets[i..i + c.len_utf8()], "a bracket")),
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn first_illegal_char(
+    line: &[(BracketType, Orientation)],
+) -> Result<Vec<BracketType>, BracketType> {
+    let mut stack = Vec::new();
+    for (bracket, orientation) in line {
+        match orientation {
+            Orientation::Open => stack.push(*bracket),
+            Orientation::Close => {
+                let matching = stack.pop();
+                if matching != Some(*bracket) {
+                    return Err(*bracket);
+                }
+            }
+        }
+    }
+    Ok(stack)
+}
+
+fn step_1(lines: &[Vec<(BracketType, Orientation)>]) -> u64 {
+    let mut illegals = ArrayMap::<_, _, 4>::new();
+    for line in lines {
+        match first_illegal_char(line) {
+            Ok(_) => continue,
+            Err(b) => {
+                *illegals.entry(b).or_insert(0) += 1;
+            }
+        }
+    }
+    illegals
+        .iter()
+        .map(|(bracket, count)| {
+            count
+                * match bracket {
+                    BracketType::Parenthesis => 3,
+                    BracketType::Straight => 57,
+                    BracketType::Curly => 1197,
+                    BracketType::Angle => 25137,
+                }
+        })
+        .sum()
+}
+
+fn step_2(lines: &[Vec<(BracketType, Orientation)>]) -> u64 {
+    let mut lines_scores = Vec::new();
+    for line in lines {
+        match first_illegal_char(line) {
+            Ok(remaining_stack) if !remaining_stack.is_empty() => {
+                let mut score: u64 = 0;
+                for left in remaining_stack.iter().rev() {
+                    score *= 5;
+                    score += match left {
+                        BracketType::Parenthesis => 1,
+                        BracketType::Straight => 2,
+                        BracketType::Curly => 3,
+                        BracketType::Angle => 4,
+                    };
+                }
+                lines_scores.push(score);
+            }
+            Ok(_) | Err(_) => continue,
+        }
+    }
+    lines_scores.sort();
+    assert!(lines_scores.len() % 2 == 1);
+    lines_scores[lines_scores.len() / 2]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Vec<(BracketType, Orientation)>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
@@ -0,0 +1,88 @@
+use matrix::Matrix;
+use solution::{Answer, ParseError, Solution};
+
+pub type Grid = Matrix<u8>;
+
+fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    Ok(Matrix::from_digit_str(input)?)
+}
+
+fn step(g: &mut Grid) -> usize {
+    let mut activated: Matrix<bool> = Matrix::default_with_size(g.dims());
+    let mut activations = Vec::new();
+    for v in g.iter_mut() {
+        *v += 1;
+    }
+
+    for (i, j) in g.iter_coords() {
+        if *g.get(i, j).unwrap() > 9 {
+            *activated.get_mut(i, j).unwrap() = true;
+            activations.push((i, j));
+        }
+    }
+    while let Some((i, j)) = activations.pop() {
+        for neigh in g.neighbors_diag_pos(i, j) {
+            let neigh_value = g.get_mut(neigh.0, neigh.1).unwrap();
+            if *activated.get(neigh.0, neigh.1).unwrap() {
+                continue;
+            }
+            *neigh_value += 1;
+            if *neigh_value > 9 {
+                *activated.get_mut(neigh.0, neigh.1).unwrap() = true;
+                activations.push(neigh);
+            }
+        }
+    }
+
+    let mut number_of_activations = 0;
+    for (i, j) in g.iter_coords() {
+        let v = g.get_mut(i, j).unwrap();
+        if *v > 9 {
+            *v = 0;
+            number_of_activations += 1;
+        }
+    }
+    number_of_activations
+}
+
+fn step_1(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    let mut total_activations = 0;
+    for _ in 0..100 {
+        total_activations += step(&mut grid);
+    }
+    total_activations
+}
+
+fn step_2(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    let mut step_nb = 0;
+    loop {
+        let activations = step(&mut grid);
+        step_nb += 1;
+        if activations == grid.dims().0 * grid.dims().1 {
+            break;
+        }
+    }
+    step_nb
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
@@ -0,0 +1,236 @@
+use solution::{Answer, Line, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+use arrayvec::{ArrayStr, BitSet};
+
+/// Caves are tracked in a `BitSet` of their indices.
+const MAX_CAVES: usize = 64;
+
+type Visited = BitSet<MAX_CAVES, u64>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Node {
+    Start,
+    End,
+    Big(ArrayStr<2>),
+    Small(ArrayStr<2>),
+}
+
+impl Node {
+    fn parse<'a>(line: &Line<'a>, s: &'a str) -> Result<Self, ParseError> {
+        Ok(match s {
+            "start" => Self::Start,
+            "end" => Self::End,
+            _ => {
+                let name = ArrayStr::try_from(s)
+                    .map_err(|_| line.error(s, "a cave name of at most 2 letters"))?;
+                if s.chars().all(char::is_lowercase) {
+                    Self::Small(name)
+                } else if s.chars().all(char::is_uppercase) {
+                    Self::Big(name)
+                } else {
+                    return Err(line.error(s, "a lowercase or uppercase cave name"));
+                }
+            }
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Node, Node)>, ParseError> {
+    let mut caves = HashSet::new();
+    let mut lines = solution::lines(input);
+    let edges = lines
+        .by_ref()
+        .map(|l| {
+            let (left, right) = l.split_once(l.text.trim(), "-")?;
+            let edge = (Node::parse(&l, left)?, Node::parse(&l, right)?);
+            for (node, name) in [(&edge.0, left), (&edge.1, right)] {
+                if caves.insert(node.clone()) && caves.len() > MAX_CAVES {
+                    return Err(l.error(name, format!("at most {} caves", MAX_CAVES)));
+                }
+            }
+            Ok(edge)
+        })
+        .collect::<Result<_, _>>()?;
+
+    for (cave, name) in [(Node::Start, "start"), (Node::End, "end")] {
+        if !caves.contains(&cave) {
+            // All the lines are read, so this is an error at the end of input.
+            return Err(lines
+                .expect(format!("a path through the '{}' cave", name))
+                .unwrap_err());
+        }
+    }
+    Ok(edges)
+}
+
+/// The index of each cave, below `MAX_CAVES` once parsed.
+fn cave_indices(edges: &[(Node, Node)]) -> HashMap<Node, usize> {
+    let mut indices = HashMap::new();
+    for node in edges.iter().flat_map(|(start, finish)| [start, finish]) {
+        let next = indices.len();
+        indices.entry(node.clone()).or_insert(next);
+    }
+    indices
+}
+
+fn adajacency_matrix(edges: &[(Node, Node)]) -> HashMap<Node, Vec<Node>> {
+    let mut matrix: HashMap<Node, Vec<Node>> = HashMap::new();
+    for (start, finish) in edges {
+        matrix
+            .entry(start.clone())
+            .or_default()
+            .push(finish.clone());
+        matrix
+            .entry(finish.clone())
+            .or_default()
+            .push(start.clone());
+    }
+    matrix
+}
+
+fn path_next_child(path: &mut [(Node, usize)]) {
+    if let Some((_, i)) = path.last_mut() {
+        *i += 1;
+    }
+}
+
+fn step_1(edges: &[(Node, Node)]) -> u64 {
+    let adjacent = adajacency_matrix(edges);
+    let index = cave_indices(edges);
+    let mut path = Vec::new();
+    let mut encountered = Visited::new();
+
+    path.push((Node::Start, 0));
+    encountered.insert(index[&Node::Start]);
+
+    let mut n_paths = 0;
+
+    while let Some((current_node, next_child)) = path.last().cloned() {
+        let neighs = &adjacent[&current_node];
+        if neighs.len() <= next_child {
+            let (node, _) = path.pop().unwrap();
+            encountered.remove(index[&node]);
+            path_next_child(&mut path);
+            continue;
+        }
+
+        let next_node = neighs[next_child].clone();
+
+        match next_node {
+            Node::Start => {
+                path_next_child(&mut path);
+                continue;
+            }
+            Node::End => {
+                path_next_child(&mut path);
+                n_paths += 1;
+                continue;
+            }
+            Node::Small(_) if encountered.contains(index[&next_node]) => {
+                path_next_child(&mut path);
+                continue;
+            }
+            _ => {}
+        }
+
+        encountered.insert(index[&next_node]);
+        path.push((next_node, 0));
+    }
+
+    n_paths
+}
+
+fn step_2(edges: &[(Node, Node)]) -> u64 {
+    let adjacent = adajacency_matrix(edges);
+    let index = cave_indices(edges);
+    let mut path = Vec::new();
+    let mut encountered = Visited::new();
+
+    path.push((Node::Start, 0));
+    encountered.insert(index[&Node::Start]);
+
+    let mut n_paths = 0;
+    let mut visited_twice = None;
+
+    while let Some((current_node, next_child)) = path.last().cloned() {
+        let neighs = &adjacent[&current_node];
+        if neighs.len() <= next_child {
+            let (node, _) = path.pop().unwrap();
+            if visited_twice.as_ref() == Some(&node) {
+                visited_twice = None;
+            } else {
+                encountered.remove(index[&node]);
+            }
+            path_next_child(&mut path);
+            continue;
+        }
+
+        let next_node = neighs[next_child].clone();
+
+        match next_node {
+            Node::Start => {
+                path_next_child(&mut path);
+                continue;
+            }
+            Node::End => {
+                path_next_child(&mut path);
+                n_paths += 1;
+                continue;
+            }
+            Node::Small(_) if encountered.contains(index[&next_node]) => {
+                if visited_twice.is_none() {
+                    visited_twice = Some(next_node.clone());
+                } else {
+                    path_next_child(&mut path);
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        encountered.insert(index[&next_node]);
+        path.push((next_node, 0));
+    }
+
+    n_paths
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<(Node, Node)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_caves() {
+        let error = parse_input("A-b\nb-end\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "3:1: expected a path through the 'start' cave"
+        );
+        let error = parse_input("start-A\nA-b").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "3:1: expected a path through the 'end' cave"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use solution::{Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+pub type Point = (usize, usize);
+
+fn parse_input(input: &str) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+    let mut lines = solution::lines(input);
+    let positions = lines
+        .by_ref()
+        .take_while(|l| !l.text.trim().is_empty())
+        .map(|l| {
+            let (left, right) = l.split_once(l.text.trim(), ",")?;
+            Ok((
+                l.parse(left, "a coordinate")?,
+                l.parse(right, "a coordinate")?,
+            ))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let folds = lines
+        .map(|l| {
+            let text = l.text.trim();
+            let fold = l.strip_prefix(text, "fold along ")?;
+            let (f, pos) = l.split_once(fold, "=")?;
+            Ok(match f {
+                "x" => Fold::X(l.parse(pos, "a position")?),
+                "y" => Fold::Y(l.parse(pos, "a position")?),
+                _ => return Err(l.error(f, "'x' or 'y'")),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((positions, folds))
+}
+
+fn fold_coord(coord: usize, fold: usize) -> usize {
+    if coord < fold {
+        coord
+    } else {
+        (2 * fold) - coord
+    }
+}
+
+fn apply_fold(positions: &[Point], fold: Fold) -> Vec<Point> {
+    let mut new_points: Vec<_> = positions
+        .iter()
+        .map(|&(x, y)| match fold {
+            Fold::X(f) => (fold_coord(x, f), y),
+            Fold::Y(f) => (x, fold_coord(y, f)),
+        })
+        .collect();
+
+    new_points.sort();
+    new_points.dedup();
+
+    new_points
+}
+
+fn to_grid(positions: &[Point]) -> matrix::Matrix<char> {
+    let dim_x = positions.iter().max_by_key(|(x, _)| *x).unwrap().0;
+    let dim_y = positions.iter().max_by_key(|(_, y)| *y).unwrap().1;
+
+    let mut mat = matrix::Matrix::new_with_elem((dim_x + 1, dim_y + 1), ' ');
+    for &(x, y) in positions {
+        *mat.get_mut(x, y).unwrap() = '*';
+    }
+    mat
+}
+
+fn first_step(positions: &[Point], folds: &[Fold]) -> usize {
+    let first = apply_fold(positions, folds[0]);
+
+    first.len()
+}
+
+fn second_step(positions: &[Point], folds: &[Fold]) -> String {
+    let mut positions = positions;
+    let mut buff;
+    for &f in folds {
+        buff = apply_fold(positions, f);
+        positions = &buff;
+    }
+
+    let grid = to_grid(positions);
+
+    grid.to_string()
+}
+
+fn step_1((positions, folds): &(Vec<Point>, Vec<Fold>)) -> usize {
+    first_step(positions, folds)
+}
+
+fn step_2((positions, folds): &(Vec<Point>, Vec<Fold>)) -> String {
+    second_step(positions, folds)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (Vec<Point>, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
@@ -0,0 +1,108 @@
+use solution::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+pub type Combination = ([char; 2], char);
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Vec<Combination>), ParseError> {
+    let mut lines = solution::lines(input);
+
+    let start = lines
+        .expect("a polymer template")?
+        .text
+        .trim()
+        .chars()
+        .collect();
+    lines.expect("an empty line")?;
+
+    let combinations = lines
+        .map(|l| {
+            let (from, to) = l.split_once(l.text, "->")?;
+            let (from, to) = (from.trim(), to.trim());
+
+            let (&[a, b], &[c]) = (
+                from.chars().collect::<Vec<_>>().as_slice(),
+                to.chars().collect::<Vec<_>>().as_slice(),
+            ) else {
+                return Err(if from.chars().count() != 2 {
+                    l.error(from, "a pair of elements")
+                } else {
+                    l.error(to, "an element")
+                });
+            };
+            Ok(([a, b], c))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((start, combinations))
+}
+
+fn count_new_pairs(
+    pair_counts: &HashMap<[char; 2], u64>,
+    next_pair_count: &mut HashMap<[char; 2], u64>,
+    combinatiosn: &HashMap<[char; 2], char>,
+) {
+    for (pair, count) in pair_counts {
+        let Some(&middle) = combinatiosn.get(pair) else {
+            *next_pair_count.entry(*pair).or_insert(0) += *count;
+            continue;
+        };
+
+        *next_pair_count.entry([pair[0], middle]).or_insert(0) += *count;
+        *next_pair_count.entry([middle, pair[1]]).or_insert(0) += *count;
+    }
+}
+
+fn max_min_chars(rounds: usize, start: &[char], combinations: &[([char; 2], char)]) -> u64 {
+    let combinations: HashMap<_, _> = combinations.iter().copied().collect();
+    let mut pair_count = start
+        .array_windows::<2>()
+        .fold(HashMap::new(), |mut h, &p| {
+            *h.entry(p).or_insert(0_u64) += 1;
+            h
+        });
+    let mut next_count = HashMap::new();
+
+    for _ in 0..rounds {
+        next_count.clear();
+        count_new_pairs(&pair_count, &mut next_count, &combinations);
+        std::mem::swap(&mut next_count, &mut pair_count);
+    }
+    let mut counts: HashMap<_, u64> = HashMap::new();
+    for ([_, e], c) in pair_count {
+        *counts.entry(e).or_default() += c;
+    }
+    *counts.get_mut(&start[0]).unwrap() += 1;
+
+    let max = counts.iter().max_by_key(|(_, count)| *count).unwrap().1;
+    let min = counts.iter().min_by_key(|(_, count)| *count).unwrap().1;
+
+    max - min
+}
+
+fn step_1((start, combinations): &(Vec<char>, Vec<Combination>)) -> u64 {
+    max_min_chars(10, start, combinations)
+}
+
+fn step_2((start, combinations): &(Vec<char>, Vec<Combination>)) -> u64 {
+    max_min_chars(40, start, combinations)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = (Vec<char>, Vec<Combination>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
@@ -0,0 +1,116 @@
+use solution::{Answer, Line, ParseError, Solution};
+
+pub type Pixel = u8;
+pub type Image = matrix::Matrix<Pixel>;
+
+fn parse_input(input: &str) -> Result<(Vec<Pixel>, Image), ParseError> {
+    fn parse_pixels(l: &Line) -> Result<Vec<Pixel>, ParseError> {
+        let symbols = l.text.trim();
+        symbols
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(0),
+                '#' => Ok(1),
+                _ => Err(l.error(&symbols[i..i + c.len_utf8()], "'.' or '#'")),
+            })
+            .collect()
+    }
+    let mut lines = solution::lines(input);
+    let codec = parse_pixels(&lines.expect("the image enhancement algorithm")?)?;
+    let blank = lines.expect("an empty line")?;
+    if !blank.text.trim().is_empty() {
+        return Err(blank.error(blank.text, "an empty line"));
+    }
+    let im = Image::from_str_with(lines.rest(), |c| match c {
+        '.' => Ok(0),
+        '#' => Ok(1),
+        _ => Err("'.' or '#'"),
+    })
+    .map_err(|e| lines.grid_error(e))?;
+    Ok((codec, im))
+}
+
+fn expand_image(im: &Image) -> Image {
+    let mut new = Image::default_with_size((im.dims().0 + 4, im.dims().1 + 4));
+    new.view_mut((2, 2), im.dims())
+        .clone_from_view(im.as_view());
+    new
+}
+
+fn coord_to_new_val(im: &Image, codec: &[Pixel], i: usize, j: usize) -> Pixel {
+    assert!(i > 0);
+    assert!(j > 0);
+    let mut coord = 0_usize;
+    for k in [j - 1, j, j + 1] {
+        for p in [i - 1, i, i + 1] {
+            coord <<= 1;
+            coord |= *im.get(p, k).unwrap() as usize;
+        }
+    }
+    codec[coord]
+}
+
+fn background_change(bg: Pixel, codec: &[Pixel]) -> Pixel {
+    let coord = if bg == 0 { 0 } else { (1 << 9) - 1 };
+    codec[coord]
+}
+
+fn apply_decompression(im: &Image, codec: &[Pixel], background: Pixel) -> Image {
+    let mut new = Image::new_with_elem((im.dims().0 + 4, im.dims().1 + 4), background);
+    for i in 1..(im.dims().0 - 1) {
+        for j in 1..(im.dims().1 - 1) {
+            *new.get_mut(i + 2, j + 2).unwrap() = coord_to_new_val(im, codec, i, j);
+        }
+    }
+    new
+}
+
+fn first_step(im: &Image, codec: &[Pixel]) -> usize {
+    let mut im = expand_image(im);
+    let mut background = 0;
+    for _ in 0..2 {
+        background = background_change(background, codec);
+        im = apply_decompression(&im, codec, background);
+    }
+
+    im.iter().filter(|&l| *l > 0).count()
+}
+
+fn second_step(im: &Image, codec: &[Pixel]) -> usize {
+    let mut im = expand_image(im);
+    let mut background = 0;
+    for _ in 0..50 {
+        background = background_change(background, codec);
+        im = apply_decompression(&im, codec, background);
+    }
+
+    im.iter().filter(|&l| *l > 0).count()
+}
+
+fn step_1((codec, image): &(Vec<Pixel>, Image)) -> usize {
+    first_step(image, codec)
+}
+
+fn step_2((codec, image): &(Vec<Pixel>, Image)) -> usize {
+    second_step(image, codec)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = (Vec<Pixel>, Image);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
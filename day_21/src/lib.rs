@@ -0,0 +1,117 @@
+use solution::{Answer, ParseError, Solution};
+use std::collections::{BTreeMap, HashMap};
+
+fn parse_input(input: &str) -> Result<[u8; 2], ParseError> {
+    let mut lines = solution::lines(input);
+    let mut starting_pos = [0; 2];
+    for pos in &mut starting_pos {
+        let l = lines.expect("a player's starting position")?;
+        let text = l.strip_prefix(l.text.trim(), "Player ")?;
+        let (_player_nb, rest) = l.split_once(text, " ")?;
+        *pos = l.parse(l.strip_prefix(rest, "starting position: ")?, "a position")?;
+    }
+    Ok(starting_pos)
+}
+
+fn step_1(starting_pos: &[u8; 2]) -> u64 {
+    let mut positions: Vec<_> = starting_pos.iter().map(|p| (*p, 0_u16)).collect();
+    let mut dice: u64 = 1;
+    'outer: loop {
+        for (pos, score) in &mut positions {
+            let advance = ((dice * 3 + 3) % 10) as u8;
+            dice += 3;
+            *pos = (*pos + advance - 1) % 10 + 1;
+            *score += *pos as u16;
+            if *score >= 1000 {
+                break 'outer;
+            }
+        }
+    }
+    (dice - 1) * positions.iter().map(|(_, s)| *s).min().unwrap() as u64
+}
+
+#[allow(dead_code)]
+fn multinomial() {
+    let mut counts = HashMap::new();
+    for i in 1..4 {
+        for j in 1..4 {
+            for k in 1..4 {
+                *counts.entry(i + j + k).or_insert(0) += 1;
+            }
+        }
+    }
+    dbg!(counts);
+}
+
+const MUTINOMIAL_DICES: [(u8, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+#[derive(Debug, PartialEq, Eq)]
+struct Universe([(u8, u8); 2]);
+
+impl std::cmp::Ord for Universe {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.0[0].0 + self.0[1].0).cmp(&(other.0[0].0 + other.0[1].0)) {
+            std::cmp::Ordering::Equal => self.0.cmp(&other.0),
+            other => other,
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for Universe {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn advance(roll: u8, p_state: (u8, u8)) -> (u8, u8) {
+    let next_pos = (p_state.1 + roll - 1) % 10 + 1;
+    let next_score = p_state.0 + next_pos;
+    (next_score, next_pos)
+}
+
+fn step_2(starting_pos: &[u8; 2]) -> u64 {
+    let mut players_wins = [0, 0];
+    let mut universes = BTreeMap::new();
+
+    universes.insert(Universe([(0, starting_pos[0]), (0, starting_pos[1])]), 1);
+    while let Some((Universe(universe), count)) = universes.pop_first() {
+        for (d1_roll, d1_counts) in MUTINOMIAL_DICES {
+            let next_p1 = advance(d1_roll, universe[0]);
+            if next_p1.0 >= 21 {
+                players_wins[0] += d1_counts * count;
+                continue;
+            }
+            for (d2_roll, d2_counts) in MUTINOMIAL_DICES {
+                let next_p2 = advance(d2_roll, universe[1]);
+                if next_p2.0 >= 21 {
+                    players_wins[1] += d1_counts * d2_counts * count;
+                    continue;
+                }
+                let next_universe = [next_p1, next_p2];
+                *universes.entry(Universe(next_universe)).or_default() +=
+                    d1_counts * d2_counts * count;
+            }
+        }
+    }
+    players_wins.iter().copied().max().unwrap()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = [u8; 2];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        step_2(input).into()
+    }
+}
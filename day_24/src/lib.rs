@@ -0,0 +1,212 @@
+use solution::{Answer, Line, ParseError, Solution};
+
+type Val = i32;
+
+#[derive(Debug)]
+struct RegisterState([Val; 4]);
+
+impl RegisterState {
+    #[allow(dead_code)]
+    fn new() -> Self {
+        Self([0; 4])
+    }
+    fn get_op(&self, op: &Op<Reg>) -> Val {
+        match op {
+            Op::Const(c) => *c,
+            Op::Register(reg) => self.get_reg(reg),
+        }
+    }
+
+    fn get_reg(&self, reg: &Reg) -> Val {
+        self.0[reg.to_pos()]
+    }
+
+    fn set(&mut self, reg: &Reg, val: Val) {
+        self.0[reg.to_pos()] = val
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Reg {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+#[allow(dead_code)]
+struct UniqueReg {
+    id: i32,
+    reg: Reg,
+}
+
+pub enum Op<R> {
+    Register(R),
+    Const(i32),
+}
+
+impl Reg {
+    fn to_pos(self) -> usize {
+        match self {
+            Reg::W => 0,
+            Reg::X => 1,
+            Reg::Y => 2,
+            Reg::Z => 3,
+        }
+    }
+}
+
+pub enum Instruction<U, V> {
+    Inp(U),
+    Add((U, V)),
+    Mul((U, V)),
+    Div((U, V)),
+    Mod((U, V)),
+    Eql((U, V)),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction<Reg, Op<Reg>>>, ParseError> {
+    fn parse_reg(l: &Line, reg: &str) -> Result<Reg, ParseError> {
+        Ok(match reg {
+            "w" => Reg::W,
+            "x" => Reg::X,
+            "y" => Reg::Y,
+            "z" => Reg::Z,
+            _ => return Err(l.error(reg, "a register")),
+        })
+    }
+    fn parse_op<'a>(l: &Line<'a>, op: &'a str) -> Result<Op<Reg>, ParseError> {
+        Ok(match op {
+            "w" | "x" | "y" | "z" => Op::Register(parse_reg(l, op)?),
+            val => Op::Const(l.parse(val, "a register or a number")?),
+        })
+    }
+
+    fn parse_two_reg<'a>(l: &Line<'a>, input: &'a str) -> Result<(Reg, Op<Reg>), ParseError> {
+        let (f, s) = l.split_once(input, " ")?;
+        Ok((parse_reg(l, f)?, parse_op(l, s)?))
+    }
+
+    solution::lines(input)
+        .map(|l| {
+            let (instr, regs) = l.split_once(l.text.trim(), " ")?;
+            Ok(match instr {
+                "inp" => Instruction::Inp(parse_reg(&l, regs)?),
+                "add" => Instruction::Add(parse_two_reg(&l, regs)?),
+                "mul" => Instruction::Mul(parse_two_reg(&l, regs)?),
+                "div" => Instruction::Div(parse_two_reg(&l, regs)?),
+                "mod" => Instruction::Mod(parse_two_reg(&l, regs)?),
+                "eql" => Instruction::Eql(parse_two_reg(&l, regs)?),
+                _ => return Err(l.error(instr, "an instruction")),
+            })
+        })
+        .collect()
+}
+
+fn eval_instruction(
+    instr: &Instruction<Reg, Op<Reg>>,
+    mut inputs: impl Iterator<Item = Val>,
+    state: &mut RegisterState,
+) -> Option<()> {
+    match instr {
+        Instruction::Add((r1, r2)) => state.set(r1, state.get_reg(r1) + state.get_op(r2)),
+        Instruction::Div((r1, r2)) => state.set(r1, state.get_reg(r1) / state.get_op(r2)),
+        Instruction::Eql((r1, r2)) => state.set(
+            r1,
+            if state.get_reg(r1) == state.get_op(r2) {
+                1
+            } else {
+                0
+            },
+        ),
+        Instruction::Inp(r1) => state.set(r1, inputs.next().unwrap()),
+        Instruction::Mod((r1, r2)) => state.set(r1, state.get_reg(r1) % state.get_op(r2)),
+        Instruction::Mul((r1, r2)) => state.set(r1, state.get_reg(r1) * state.get_op(r2)),
+    }
+    Some(())
+}
+
+#[allow(dead_code)]
+fn eval_instructions(instrs: &[Instruction<Reg, Op<Reg>>], inputs: &[i32]) -> RegisterState {
+    let mut state = RegisterState([0; 4]);
+    let mut inputs = inputs.iter().copied();
+    for instr in instrs {
+        eval_instruction(instr, &mut inputs, &mut state);
+    }
+    state
+}
+
+#[allow(dead_code)]
+fn instr_to_unique(
+    instrs: &[Instruction<Reg, Op<Reg>>],
+) -> Vec<Instruction<UniqueReg, Op<UniqueReg>>> {
+    fn map_reg(reg_state: &mut RegisterState, reg: &Reg, incr: bool) -> UniqueReg {
+        let mut id = reg_state.get_reg(reg);
+        if incr {
+            id += 1;
+            reg_state.set(reg, id)
+        }
+        UniqueReg { id, reg: *reg }
+    }
+    fn map_op(reg_state: &mut RegisterState, op: &Op<Reg>) -> Op<UniqueReg> {
+        match op {
+            Op::Const(c) => Op::Const(*c),
+            Op::Register(reg) => Op::Register(map_reg(reg_state, reg, false)),
+        }
+    }
+    let mut reg_state = RegisterState::new();
+    instrs
+        .iter()
+        .map(|i| {
+            use Instruction::*;
+            match i {
+                Inp(r) => Inp(map_reg(&mut reg_state, r, true)),
+                Add((r, o)) => {
+                    let operand = map_op(&mut reg_state, o);
+                    let receiver = map_reg(&mut reg_state, r, true);
+                    Add((receiver, operand))
+                }
+                Mul((r, o)) => {
+                    let operand = map_op(&mut reg_state, o);
+                    let receiver = map_reg(&mut reg_state, r, true);
+                    Mul((receiver, operand))
+                }
+                Div((r, o)) => {
+                    let operand = map_op(&mut reg_state, o);
+                    let receiver = map_reg(&mut reg_state, r, true);
+                    Div((receiver, operand))
+                }
+                Mod((r, o)) => {
+                    let operand = map_op(&mut reg_state, o);
+                    let receiver = map_reg(&mut reg_state, r, true);
+                    Mod((receiver, operand))
+                }
+                Eql((r, o)) => {
+                    let operand = map_op(&mut reg_state, o);
+                    let receiver = map_reg(&mut reg_state, r, true);
+                    Eql((receiver, operand))
+                }
+            }
+        })
+        .collect()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Instruction<Reg, Op<Reg>>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(_: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,122 @@
+use solution::{Answer, ParseError, Solution};
+
+pub type Grid = matrix::Matrix<Case>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Case(Option<Direction>);
+
+impl std::fmt::Display for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self.0 {
+            None => ".",
+            Some(Direction::East) => ">",
+            Some(Direction::South) => "v",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Direction {
+    East,
+    South,
+}
+
+impl Direction {
+    #[inline(always)]
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    Ok(Grid::from_str_with(input, |c| {
+        Ok(Case(match c {
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '.' => None,
+            _ => return Err("'>', 'v' or '.'"),
+        }))
+    })?)
+}
+
+fn perform_step(from: &Grid, to: &mut Grid, dir: Direction) -> bool {
+    let mut change = false;
+    assert_eq!(from.dims(), to.dims());
+    for (i, j) in from.iter_coords() {
+        match from.get(i, j).unwrap() {
+            Case(Some(c)) if c != &dir => {
+                *to.get_mut(i, j).unwrap() = Case(Some(*c));
+                continue;
+            }
+            Case(None) => continue,
+            _ => {}
+        }
+        let (x, y) = dir.offset();
+        let next = from.wrapping_pos(i as isize + x, j as isize + y).unwrap();
+        if let Case(None) = from.get(next.0, next.1).unwrap() {
+            // dbg!((i, j), next);
+            *to.get_mut(next.0, next.1).unwrap() = Case(Some(dir));
+            // *to.get_mut(i, j).unwrap() = Case(None);
+            change = true;
+        } else {
+            *to.get_mut(i, j).unwrap() = Case(Some(dir));
+        }
+    }
+    change
+}
+
+fn reset(g: &mut Grid) {
+    for e in g.iter_mut() {
+        *e = Case(None);
+    }
+}
+
+fn step_1(g: &Grid) -> isize {
+    let mut current_grid = g.clone();
+    let mut next_grid = Grid::default_with_size(current_grid.dims());
+    let mut step = 0;
+    // println!("\n{}\n{}", current_grid, next_grid);
+    loop {
+        reset(&mut next_grid);
+        let change_east = perform_step(&current_grid, &mut next_grid, Direction::East);
+        std::mem::swap(&mut current_grid, &mut next_grid);
+        // if !change {
+        //     break;
+        // }
+        // println!("\n{}", current_grid);
+
+        reset(&mut next_grid);
+        let change_south = perform_step(&current_grid, &mut next_grid, Direction::South);
+        std::mem::swap(&mut current_grid, &mut next_grid);
+        step += 1;
+
+        if !(change_east || change_south) {
+            break;
+        }
+        // println!("\n{}", current_grid);
+    }
+    step
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        step_1(input).into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
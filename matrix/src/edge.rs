@@ -0,0 +1,153 @@
+use crate::Matrix;
+
+/// What lies past the edges of a matrix, for the signed accessors and the
+/// neighbour iterators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Edge<T> {
+    /// Nothing, the matrix stops at its edges like with `get`.
+    #[default]
+    Clamp,
+    /// The opposite edge, as on a torus.
+    Wrap,
+    /// The same element everywhere.
+    Constant(T),
+}
+
+/// Offsets of the neighbours, in the order of `Matrix::neighbors_pos`.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the neighbours, in the order of `Matrix::neighbors_diag_pos`.
+const DIAGONAL: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Matrix<T> {
+    /// The position `(i, j)` lands on when the matrix repeats in every
+    /// direction. `None` if the matrix is empty.
+    pub fn wrapping_pos(&self, i: isize, j: isize) -> Option<(usize, usize)> {
+        let (width, height) = self.dimensions;
+        if width == 0 || height == 0 {
+            return None;
+        }
+        Some((
+            i.rem_euclid(width as isize) as usize,
+            j.rem_euclid(height as isize) as usize,
+        ))
+    }
+
+    pub fn get_wrapping(&self, i: isize, j: isize) -> Option<&T> {
+        let (i, j) = self.wrapping_pos(i, j)?;
+        self.get(i, j)
+    }
+
+    pub fn get_wrapping_mut(&mut self, i: isize, j: isize) -> Option<&mut T> {
+        let (i, j) = self.wrapping_pos(i, j)?;
+        self.get_mut(i, j)
+    }
+
+    /// `get` for positions that may be past the edges, which `edge` fills.
+    pub fn get_signed<'a>(&'a self, i: isize, j: isize, edge: &'a Edge<T>) -> Option<&'a T> {
+        match edge {
+            Edge::Wrap => self.get_wrapping(i, j),
+            _ => {
+                let inside = usize::try_from(i)
+                    .ok()
+                    .zip(usize::try_from(j).ok())
+                    .and_then(|(i, j)| self.get(i, j));
+                match edge {
+                    Edge::Constant(e) => inside.or(Some(e)),
+                    _ => inside,
+                }
+            }
+        }
+    }
+
+    /// `neighbors` with the given edge policy.
+    pub fn neighbors_with<'a>(
+        &'a self,
+        i: usize,
+        j: usize,
+        edge: &'a Edge<T>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.offsets_with(&ORTHOGONAL, i, j, edge)
+    }
+
+    /// The elements at `neighbors_diag_pos`, with the given edge policy.
+    pub fn neighbors_diag_with<'a>(
+        &'a self,
+        i: usize,
+        j: usize,
+        edge: &'a Edge<T>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.offsets_with(&DIAGONAL, i, j, edge)
+    }
+
+    /// Positions of the neighbours when the matrix wraps around.
+    pub fn neighbors_wrapping_pos(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&(x, y)| self.wrapping_pos(i as isize + x, j as isize + y))
+    }
+
+    /// `neighbors_diag_pos` when the matrix wraps around.
+    pub fn neighbors_diag_wrapping_pos(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIAGONAL
+            .iter()
+            .filter_map(move |&(x, y)| self.wrapping_pos(i as isize + x, j as isize + y))
+    }
+
+    fn offsets_with<'a>(
+        &'a self,
+        offsets: &'static [(isize, isize)],
+        i: usize,
+        j: usize,
+        edge: &'a Edge<T>,
+    ) -> impl Iterator<Item = &'a T> {
+        offsets
+            .iter()
+            .filter_map(move |&(x, y)| self.get_signed(i as isize + x, j as isize + y, edge))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edges() {
+        let m = Matrix::from_digit_str("123\n456\n").unwrap();
+        assert_eq!(m.get_wrapping(-1, 3), Some(&6));
+        assert_eq!(m.get_signed(-1, 0, &Edge::Clamp), None);
+        assert_eq!(m.get_signed(-1, 0, &Edge::Constant(0)), Some(&0));
+        assert_eq!(m.get_signed(1, 1, &Edge::Constant(0)), Some(&5));
+
+        let sum = |edge| m.neighbors_diag_with(0, 0, &edge).sum::<u8>();
+        assert_eq!(sum(Edge::Clamp), 2 + 4 + 5);
+        assert_eq!(sum(Edge::Wrap), 6 + 4 + 5 + 3 + 2 + 6 + 4 + 5);
+        assert_eq!(sum(Edge::Constant(1)), 2 + 4 + 5 + 5);
+        assert_eq!(
+            m.neighbors_with(0, 0, &Edge::Clamp).collect::<Vec<_>>(),
+            m.neighbors(0, 0).map(|(_, e)| e).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            m.neighbors_wrapping_pos(2, 1).collect::<Vec<_>>(),
+            [(1, 1), (0, 1), (2, 0), (2, 0)]
+        );
+        assert_eq!(Matrix::<u8>::new().get_wrapping(0, 0), None);
+    }
+}
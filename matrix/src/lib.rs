@@ -1,13 +1,32 @@
-#![feature(bool_to_option)]
-#![feature(mixed_integer_ops)]
 use std::fmt::{Debug, Display};
 
+mod edge;
+mod parse;
+mod transform;
+mod view;
+
+pub use edge::Edge;
+pub use parse::{GridError, GridErrorKind};
+pub use transform::{Axis, TransformedView};
+pub use view::{MatrixView, MatrixViewMut};
+
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "serde_impls::RawMatrix<T>")
+)]
 pub struct Matrix<T> {
     dimensions: (usize, usize),
     storage: Vec<T>,
 }
 
+impl<T> Default for Matrix<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Matrix<T> {
     pub fn new() -> Self {
         Self {
@@ -20,7 +39,7 @@ impl<T> Matrix<T> {
         self.dimensions
     }
 
-    pub fn next_row(&mut self) -> MatrixRow<T> {
+    pub fn next_row(&mut self) -> MatrixRow<'_, T> {
         MatrixRow {
             mat: self,
             row_size: 0,
@@ -50,11 +69,7 @@ impl<T> Matrix<T> {
             .chain((j + 1 < self.dimensions.1).then(|| (i, j + 1)))
     }
 
-    pub fn neighbors_diag_pos<'a>(
-        &'a self,
-        i: usize,
-        j: usize,
-    ) -> impl Iterator<Item = (usize, usize)> {
+    pub fn neighbors_diag_pos(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> {
         let mut start_x = -1;
         let mut start_y = -1;
         let dims = self.dims();
@@ -92,32 +107,36 @@ impl<T> Matrix<T> {
     }
 
     pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
-        let mut i = 0;
-        let mut j = 0;
-        let dims = self.dims();
-        std::iter::from_fn(move || {
-            while i == dims.0 && j < dims.1 {
-                i = 0;
-                j += 1;
-            }
-            if j == dims.1 {
-                return None;
-            }
-            let pos = (i, j);
-            i += 1;
-            Some(pos)
-        })
+        coords(self.dimensions)
     }
 
-    pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &mut T> + 'a {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
         self.storage.iter_mut()
     }
 
-    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &T> + 'a {
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.storage.iter()
     }
 }
 
+/// The coordinates of a matrix of dimensions `dims`, row by row.
+fn coords(dims: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    let mut i = 0;
+    let mut j = 0;
+    std::iter::from_fn(move || {
+        while i == dims.0 && j < dims.1 {
+            i = 0;
+            j += 1;
+        }
+        if j == dims.1 {
+            return None;
+        }
+        let pos = (i, j);
+        i += 1;
+        Some(pos)
+    })
+}
+
 impl<T: Default> Matrix<T> {
     pub fn default_with_size(dimensions: (usize, usize)) -> Self {
         let mut storage = Vec::new();
@@ -202,3 +221,54 @@ impl<'a, T> Drop for MatrixRow<'a, T> {
             .truncate(self.mat.dimensions.0 * self.mat.dimensions.1);
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Matrix;
+
+    /// A deserialized `Matrix` whose dimensions are not checked yet.
+    #[derive(serde::Deserialize)]
+    pub struct RawMatrix<T> {
+        dimensions: (usize, usize),
+        storage: Vec<T>,
+    }
+
+    impl<T> TryFrom<RawMatrix<T>> for Matrix<T> {
+        type Error = String;
+
+        fn try_from(raw: RawMatrix<T>) -> Result<Self, Self::Error> {
+            let (width, height) = raw.dimensions;
+            if width.checked_mul(height) != Some(raw.storage.len()) {
+                return Err(format!(
+                    "a {}x{} matrix can't have {} elements",
+                    width,
+                    height,
+                    raw.storage.len()
+                ));
+            }
+            Ok(Matrix {
+                dimensions: raw.dimensions,
+                storage: raw.storage,
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn checks_dimensions() {
+            let m: Matrix<u8> =
+                serde_json::from_str(r#"{"dimensions":[2,1],"storage":[1,2]}"#).unwrap();
+            assert_eq!(m.get(1, 0), Some(&2));
+            assert_eq!(
+                serde_json::to_string(&m).unwrap(),
+                r#"{"dimensions":[2,1],"storage":[1,2]}"#
+            );
+            let err = serde_json::from_str::<Matrix<u8>>(r#"{"dimensions":[2,2],"storage":[1,2]}"#)
+                .unwrap_err();
+            assert_eq!(err.to_string(), "a 2x2 matrix can't have 2 elements");
+        }
+    }
+}
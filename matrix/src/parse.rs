@@ -0,0 +1,168 @@
+use std::fmt::{self, Display};
+
+use crate::Matrix;
+
+/// Error of the text grid constructors. `row` and `col` start at 0, `col`
+/// counts chars for `&str` grids and bytes for `&[u8]` ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridError<E> {
+    pub row: usize,
+    pub col: usize,
+    pub kind: GridErrorKind<E>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridErrorKind<E> {
+    /// The conversion function rejected `found`, `expected` is its error.
+    Invalid { found: char, expected: E },
+    /// The row ends at `col`, before the `width` of the first row.
+    TooShort { width: usize },
+    /// The row goes on with `found` at `col`, past the `width` of the first
+    /// row.
+    TooLong { width: usize, found: char },
+}
+
+impl<E: Display> GridError<E> {
+    /// The character at the error, `None` when the row is too short.
+    pub fn found(&self) -> Option<char> {
+        match self.kind {
+            GridErrorKind::Invalid { found, .. } | GridErrorKind::TooLong { found, .. } => {
+                Some(found)
+            }
+            GridErrorKind::TooShort { .. } => None,
+        }
+    }
+
+    /// What should have been at the error.
+    pub fn expected(&self) -> String {
+        match &self.kind {
+            GridErrorKind::Invalid { expected, .. } => expected.to_string(),
+            GridErrorKind::TooShort { width } | GridErrorKind::TooLong { width, .. } => {
+                format!("rows of {} elements", width)
+            }
+        }
+    }
+}
+
+impl<E: Display> Display for GridError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.row + 1,
+            self.col + 1,
+            self.expected()
+        )?;
+        if let Some(found) = self.found() {
+            write!(f, ", found {:?}", found)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: fmt::Debug + Display> std::error::Error for GridError<E> {}
+
+impl<T> Matrix<T> {
+    /// Builds a matrix from lines of characters converted by `f`, which
+    /// returns what it expected when it rejects a character. Whitespace at
+    /// the end of each row and newlines at the end of `s` are ignored, any
+    /// other empty line is a row too short.
+    pub fn from_str_with<E, F>(s: &str, mut f: F) -> Result<Self, GridError<E>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Self::from_rows(
+            s.trim_end_matches(['\n', '\r'])
+                .lines()
+                .map(|l| l.trim_end().chars()),
+            |c| (c, f(c)),
+        )
+    }
+
+    /// `from_str_with` for byte grids, ignoring ASCII whitespace at the end
+    /// of each row.
+    pub fn from_bytes_with<E, F>(s: &[u8], mut f: F) -> Result<Self, GridError<E>>
+    where
+        F: FnMut(u8) -> Result<T, E>,
+    {
+        let end = s
+            .iter()
+            .rposition(|b| !matches!(b, b'\n' | b'\r'))
+            .map_or(0, |i| i + 1);
+        let rows = s[..end]
+            .split(|&b| b == b'\n')
+            .map(|row| row.trim_ascii_end().iter().copied());
+        Self::from_rows(rows, |b| (b as char, f(b)))
+    }
+
+    fn from_rows<U, R, E, F>(rows: impl Iterator<Item = R>, mut f: F) -> Result<Self, GridError<E>>
+    where
+        R: Iterator<Item = U>,
+        F: FnMut(U) -> (char, Result<T, E>),
+    {
+        let mut matrix = Self::new();
+        for (row, elements) in rows.enumerate() {
+            let mut col = 0;
+            for element in elements {
+                let (found, value) = f(element);
+                if row > 0 && col == matrix.dimensions.0 {
+                    let width = matrix.dimensions.0;
+                    let kind = GridErrorKind::TooLong { width, found };
+                    return Err(GridError { row, col, kind });
+                }
+                let value = value.map_err(|expected| GridError {
+                    row,
+                    col,
+                    kind: GridErrorKind::Invalid { found, expected },
+                })?;
+                matrix.storage.push(value);
+                col += 1;
+            }
+            if row == 0 {
+                matrix.dimensions.0 = col;
+            } else if col < matrix.dimensions.0 {
+                let width = matrix.dimensions.0;
+                let kind = GridErrorKind::TooShort { width };
+                return Err(GridError { row, col, kind });
+            }
+            matrix.dimensions.1 += 1;
+        }
+        Ok(matrix)
+    }
+}
+
+impl Matrix<u8> {
+    /// Builds a matrix of single digits.
+    pub fn from_digit_str(s: &str) -> Result<Self, GridError<&'static str>> {
+        Self::from_str_with(s, |c| c.to_digit(10).map(|d| d as u8).ok_or("a digit"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_errors() {
+        let m = Matrix::from_digit_str("12\n34\n").unwrap();
+        assert_eq!((m.dims(), m.get(0, 1)), ((2, 2), Some(&3)));
+
+        let err = Matrix::from_digit_str("12\n3x\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:2: expected a digit, found 'x'");
+        let err = Matrix::from_digit_str("12\n345").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2:3: expected rows of 2 elements, found '5'"
+        );
+        let err = Matrix::from_bytes_with(b"..\r\n. \r\n", Ok::<_, &str>).unwrap_err();
+        assert_eq!((err.row, err.col, err.found()), (1, 1, None));
+        let err = Matrix::from_digit_str("12\n\n34").unwrap_err();
+        assert_eq!((err.row, err.col, err.found()), (1, 0, None));
+
+        let bytes = Matrix::from_bytes_with(b"12 \n34\t\n\n", |b| Ok::<_, &str>(b - b'0'));
+        assert_eq!(
+            bytes.unwrap().storage,
+            Matrix::from_digit_str("12 \n34\t\n\n").unwrap().storage
+        );
+    }
+}
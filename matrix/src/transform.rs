@@ -0,0 +1,264 @@
+use crate::{coords, Matrix, MatrixView};
+
+/// A `MatrixView` seen through a rotation or a reflection, without copying
+/// it. Built by the transforms of `MatrixView`, and composable with them.
+#[derive(Debug)]
+pub struct TransformedView<'a, T> {
+    view: MatrixView<'a, T>,
+    // Maps a coordinate of the transformed view to one of `view` by
+    // flipping it in the transformed space, then swapping the axes.
+    flip_x: bool,
+    flip_y: bool,
+    transposed: bool,
+}
+
+impl<T> Clone for TransformedView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TransformedView<'_, T> {}
+
+/// The axis a `fold_along` line is perpendicular to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// A vertical line, `x = line`.
+    X,
+    /// A horizontal line, `y = line`.
+    Y,
+}
+
+macro_rules! transforms {
+    ($($(#[$doc:meta])* $name:ident => ($transposed:literal, $flip_x:literal, $flip_y:literal),)*) => {
+        impl<'a, T> MatrixView<'a, T> {
+            $(
+                $(#[$doc])*
+                pub fn $name(self) -> TransformedView<'a, T> {
+                    TransformedView::new(self).$name()
+                }
+            )*
+        }
+
+        impl<'a, T> TransformedView<'a, T> {
+            $(
+                $(#[$doc])*
+                pub fn $name(self) -> Self {
+                    self.then($transposed, $flip_x, $flip_y)
+                }
+            )*
+        }
+
+        impl<T: Clone> Matrix<T> {
+            $(
+                $(#[$doc])*
+                pub fn $name(&self) -> Self {
+                    self.as_view().$name().to_matrix()
+                }
+            )*
+        }
+    };
+}
+
+transforms! {
+    /// Swaps rows and columns.
+    transpose => (true, false, false),
+    /// Rotates a quarter turn clockwise.
+    rotate_90 => (true, true, false),
+    /// Rotates a half turn.
+    rotate_180 => (false, true, true),
+    /// Rotates a quarter turn counterclockwise.
+    rotate_270 => (true, false, true),
+    /// Mirrors left and right, reversing each row.
+    flip_horizontal => (false, true, false),
+    /// Mirrors top and bottom, reversing each column.
+    flip_vertical => (false, false, true),
+}
+
+impl<'a, T> TransformedView<'a, T> {
+    fn new(view: MatrixView<'a, T>) -> Self {
+        Self {
+            view,
+            flip_x: false,
+            flip_y: false,
+            transposed: false,
+        }
+    }
+
+    /// Applies another transform after this one.
+    fn then(self, transposed: bool, flip_x: bool, flip_y: bool) -> Self {
+        // Our flips happen before the new swap of axes, so they move to the
+        // other axis.
+        let (x, y) = if transposed {
+            (self.flip_y, self.flip_x)
+        } else {
+            (self.flip_x, self.flip_y)
+        };
+        Self {
+            view: self.view,
+            flip_x: x ^ flip_x,
+            flip_y: y ^ flip_y,
+            transposed: self.transposed ^ transposed,
+        }
+    }
+
+    pub fn dims(&self) -> (usize, usize) {
+        let (width, height) = self.view.dims();
+        if self.transposed {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        let (width, height) = self.dims();
+        if i >= width || j >= height {
+            return None;
+        }
+        let i = if self.flip_x { width - 1 - i } else { i };
+        let j = if self.flip_y { height - 1 - j } else { j };
+        if self.transposed {
+            self.view.get(j, i)
+        } else {
+            self.view.get(i, j)
+        }
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        coords(self.dims())
+    }
+
+    /// The elements, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        self.iter_coords()
+            .map(move |(i, j)| view.get(i, j).unwrap())
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            dimensions: self.dims(),
+            storage: self.iter().cloned().collect(),
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Folds the matrix along `line`, like a sheet of paper: the elements on
+    /// the line are dropped, and the far side is mirrored onto the near
+    /// side. Elements landing on each other are combined by
+    /// `merge(near, far)`. The result is as large as the largest side, with
+    /// the line on its right or bottom edge. Panics if `line` is out of
+    /// bounds.
+    pub fn fold_along<F>(&self, axis: Axis, line: usize, mut merge: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        let (width, height) = self.dims();
+        let length = match axis {
+            Axis::X => width,
+            Axis::Y => height,
+        };
+        assert!(
+            line < length,
+            "fold line {} out of a matrix {} long along {:?}",
+            line,
+            length,
+            axis
+        );
+        let folded = line.max(length - line - 1);
+        let dimensions = match axis {
+            Axis::X => (folded, height),
+            Axis::Y => (width, folded),
+        };
+        let mut storage = Vec::with_capacity(dimensions.0 * dimensions.1);
+        match axis {
+            Axis::X => {
+                for row in self.rows() {
+                    fold_slice(row, line, folded, &mut merge, &mut storage);
+                }
+            }
+            Axis::Y => {
+                for distance in (1..=folded).rev() {
+                    let near = (distance <= line).then(|| self.row(line - distance));
+                    let far = (line + distance < height).then(|| self.row(line + distance));
+                    match (near, far) {
+                        (Some(near), Some(far)) => {
+                            storage.extend(near.iter().zip(far).map(|(a, b)| merge(a, b)))
+                        }
+                        (Some(row), None) | (None, Some(row)) => storage.extend_from_slice(row),
+                        (None, None) => unreachable!(),
+                    }
+                }
+            }
+        }
+        Matrix {
+            dimensions,
+            storage,
+        }
+    }
+}
+
+/// Pushes `row` folded along `line` onto `out`, `folded` elements long.
+fn fold_slice<T: Clone, F>(row: &[T], line: usize, folded: usize, merge: &mut F, out: &mut Vec<T>)
+where
+    F: FnMut(&T, &T) -> T,
+{
+    for distance in (1..=folded).rev() {
+        // Distance from the line, on both sides.
+        let near = (distance <= line).then(|| &row[line - distance]);
+        let far = row.get(line + distance);
+        out.push(match (near, far) {
+            (Some(near), Some(far)) => merge(near, far),
+            (Some(e), None) | (None, Some(e)) => e.clone(),
+            (None, None) => unreachable!(),
+        });
+    }
+}
+
+impl<'a, T> From<MatrixView<'a, T>> for TransformedView<'a, T> {
+    fn from(view: MatrixView<'a, T>) -> Self {
+        Self::new(view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transforms() {
+        let m = Matrix::from_digit_str("123\n456\n").unwrap();
+        assert_eq!(m.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(m.rotate_90().to_string(), "41\n52\n63\n");
+        assert_eq!(m.rotate_180().to_string(), "654\n321\n");
+        assert_eq!(m.rotate_270().to_string(), "36\n25\n14\n");
+        assert_eq!(m.flip_horizontal().to_string(), "321\n654\n");
+        assert_eq!(m.flip_vertical().to_string(), "456\n123\n");
+
+        let v = m.as_view();
+        assert_eq!(
+            v.rotate_90().rotate_90().to_matrix().to_string(),
+            "654\n321\n"
+        );
+        assert_eq!(
+            v.rotate_90().flip_horizontal().to_matrix().to_string(),
+            "14\n25\n36\n"
+        );
+        assert_eq!(v.transpose().rotate_270().get(2, 1), Some(&3));
+
+        let add = |a: &u8, b: &u8| a + b;
+        assert_eq!(m.fold_along(Axis::X, 1, add).to_string(), "4\n10\n");
+        assert_eq!(m.fold_along(Axis::X, 0, add).to_string(), "32\n65\n");
+        assert_eq!(m.fold_along(Axis::Y, 1, add).to_string(), "123\n");
+
+        let m = Matrix::from_digit_str("123\n456\n789\n").unwrap();
+        assert_eq!(m.fold_along(Axis::Y, 0, add).to_string(), "789\n456\n");
+        assert_eq!(m.fold_along(Axis::Y, 2, add).to_string(), "123\n456\n");
+        assert_eq!(m.fold_along(Axis::Y, 1, add).to_string(), "81012\n");
+    }
+}
@@ -0,0 +1,331 @@
+use std::ops::Range;
+
+use crate::{coords, Matrix};
+
+/// A rectangular region of a `Matrix`, see `Matrix::view`. Coordinates are
+/// relative to the top left corner of the region.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    storage: &'a [T],
+    stride: usize,
+    offset: usize,
+    dimensions: (usize, usize),
+}
+
+/// A mutable rectangular region of a `Matrix`, see `Matrix::view_mut`.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    storage: &'a mut [T],
+    stride: usize,
+    offset: usize,
+    dimensions: (usize, usize),
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+/// Range in the storage of row `j` of a region, panics when out of bounds.
+fn row_range(stride: usize, offset: usize, dims: (usize, usize), j: usize) -> Range<usize> {
+    assert!(j < dims.1, "row {} out of a matrix of height {}", j, dims.1);
+    let start = offset + stride * j;
+    start..start + dims.0
+}
+
+/// Checks that the region at `origin` of size `dims` fits in `outer`, and
+/// returns its offset in a storage of row length `stride`.
+fn region_offset(
+    stride: usize,
+    outer: (usize, usize),
+    origin: (usize, usize),
+    dims: (usize, usize),
+) -> usize {
+    assert!(
+        origin.0 + dims.0 <= outer.0 && origin.1 + dims.1 <= outer.1,
+        "a {}x{} region at {:?} doesn't fit in a {}x{} matrix",
+        dims.0,
+        dims.1,
+        origin,
+        outer.0,
+        outer.1
+    );
+    origin.0 + stride * origin.1
+}
+
+impl<T> Matrix<T> {
+    /// The elements of row `j`. Panics if `j` is out of bounds.
+    pub fn row(&self, j: usize) -> &[T] {
+        &self.storage[row_range(self.dimensions.0, 0, self.dimensions, j)]
+    }
+
+    /// Mutable `row`.
+    pub fn row_mut(&mut self, j: usize) -> &mut [T] {
+        &mut self.storage[row_range(self.dimensions.0, 0, self.dimensions, j)]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> + DoubleEndedIterator {
+        (0..self.dimensions.1).map(move |j| self.row(j))
+    }
+
+    /// The elements of column `i`, from top to bottom. Panics if `i` is out
+    /// of bounds.
+    pub fn column(&self, i: usize) -> impl Iterator<Item = &T> {
+        self.as_view().column(i)
+    }
+
+    /// The whole matrix as a view.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            storage: &self.storage,
+            stride: self.dimensions.0,
+            offset: 0,
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// The whole matrix as a mutable view.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            storage: &mut self.storage,
+            stride: self.dimensions.0,
+            offset: 0,
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// The region of size `dims` whose top left corner is at `origin`.
+    /// Panics if it doesn't fit in the matrix.
+    pub fn view(&self, origin: (usize, usize), dims: (usize, usize)) -> MatrixView<'_, T> {
+        self.as_view().into_view(origin, dims)
+    }
+
+    /// Mutable `view`.
+    pub fn view_mut(
+        &mut self,
+        origin: (usize, usize),
+        dims: (usize, usize),
+    ) -> MatrixViewMut<'_, T> {
+        self.as_view_mut().into_view_mut(origin, dims)
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn dims(&self) -> (usize, usize) {
+        self.dimensions
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        if i >= self.dimensions.0 || j >= self.dimensions.1 {
+            return None;
+        }
+        self.storage.get(self.offset + i + self.stride * j)
+    }
+
+    /// The elements of row `j`. Panics if `j` is out of bounds.
+    pub fn row(&self, j: usize) -> &'a [T] {
+        &self.storage[row_range(self.stride, self.offset, self.dimensions, j)]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &'a [T]> + DoubleEndedIterator {
+        let view = *self;
+        (0..self.dimensions.1).map(move |j| view.row(j))
+    }
+
+    /// The elements of column `i`, from top to bottom. Panics if `i` is out
+    /// of bounds.
+    pub fn column(&self, i: usize) -> impl Iterator<Item = &'a T> {
+        assert!(
+            i < self.dimensions.0,
+            "column {} out of a matrix of width {}",
+            i,
+            self.dimensions.0
+        );
+        let view = *self;
+        (0..self.dimensions.1).map(move |j| &view.row(j)[i])
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        coords(self.dimensions)
+    }
+
+    /// The elements, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// A region of this view, see `Matrix::view`.
+    pub fn view(&self, origin: (usize, usize), dims: (usize, usize)) -> MatrixView<'a, T> {
+        self.into_view(origin, dims)
+    }
+
+    fn into_view(self, origin: (usize, usize), dims: (usize, usize)) -> Self {
+        let offset = region_offset(self.stride, self.dimensions, origin, dims);
+        Self {
+            offset: self.offset + offset,
+            dimensions: dims,
+            ..self
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            dimensions: self.dimensions,
+            storage: self.iter().cloned().collect(),
+        }
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub fn dims(&self) -> (usize, usize) {
+        self.dimensions
+    }
+
+    /// Reborrows as a shared view.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            storage: self.storage,
+            stride: self.stride,
+            offset: self.offset,
+            dimensions: self.dimensions,
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.as_view().get(i, j)
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i >= self.dimensions.0 || j >= self.dimensions.1 {
+            return None;
+        }
+        self.storage.get_mut(self.offset + i + self.stride * j)
+    }
+
+    /// The elements of row `j`. Panics if `j` is out of bounds.
+    pub fn row(&self, j: usize) -> &[T] {
+        self.as_view().row(j)
+    }
+
+    /// Mutable `row`.
+    pub fn row_mut(&mut self, j: usize) -> &mut [T] {
+        &mut self.storage[row_range(self.stride, self.offset, self.dimensions, j)]
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let (width, height) = self.dimensions;
+        let gap = self.stride - width;
+        let start = self.offset.min(self.storage.len());
+        let mut rest = &mut self.storage[start..];
+        (0..height).map(move |_| {
+            let (row, tail) = std::mem::take(&mut rest).split_at_mut(width);
+            // The last row may end the storage, without a gap after it.
+            let gap = gap.min(tail.len());
+            rest = &mut tail[gap..];
+            row
+        })
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        coords(self.dimensions)
+    }
+
+    /// The elements, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.as_view().iter()
+    }
+
+    /// Mutable `iter`.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.rows_mut().flatten()
+    }
+
+    /// A region of this view, see `Matrix::view_mut`.
+    pub fn view_mut(
+        &mut self,
+        origin: (usize, usize),
+        dims: (usize, usize),
+    ) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            storage: &mut *self.storage,
+            stride: self.stride,
+            offset: self.offset,
+            dimensions: self.dimensions,
+        }
+        .into_view_mut(origin, dims)
+    }
+
+    fn into_view_mut(self, origin: (usize, usize), dims: (usize, usize)) -> Self {
+        let offset = region_offset(self.stride, self.dimensions, origin, dims);
+        Self {
+            offset: self.offset + offset,
+            dimensions: dims,
+            ..self
+        }
+    }
+
+    /// Copies `src`, which must have the same dimensions, into this region.
+    pub fn clone_from_view(&mut self, src: MatrixView<'_, T>)
+    where
+        T: Clone,
+    {
+        assert_eq!(self.dimensions, src.dims());
+        for (dst, src) in self.rows_mut().zip(src.rows()) {
+            dst.clone_from_slice(src);
+        }
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(matrix: &'a Matrix<T>) -> Self {
+        matrix.as_view()
+    }
+}
+
+impl<'a, T> From<&'a mut Matrix<T>> for MatrixViewMut<'a, T> {
+    fn from(matrix: &'a mut Matrix<T>) -> Self {
+        matrix.as_view_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views() {
+        let mut m = Matrix::from_digit_str("123\n456\n789\n").unwrap();
+        assert_eq!(m.row(1), [4, 5, 6]);
+        assert_eq!(m.rows().len(), 3);
+        assert_eq!(m.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+
+        let v = m.view((1, 1), (2, 2));
+        assert_eq!((v.get(0, 0), v.get(2, 0)), (Some(&5), None));
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), [5, 6, 8, 9]);
+        assert_eq!(v.view((1, 0), (1, 2)).column(0).count(), 2);
+        assert_eq!(v.iter_coords().last(), Some((1, 1)));
+
+        let mut v = m.view_mut((0, 1), (2, 2));
+        *v.get_mut(1, 1).unwrap() = 0;
+        v.iter_mut().for_each(|d| *d += 1);
+        assert_eq!(m.to_string(), "123\n566\n819\n");
+        let first = m.clone();
+        m.view_mut((2, 0), (1, 3))
+            .clone_from_view(first.view((0, 0), (1, 3)));
+        assert_eq!(m.to_string(), "121\n565\n818\n");
+    }
+
+    #[test]
+    fn zero_width() {
+        let mut m = Matrix::new_with_elem((0, 3), 0);
+        assert_eq!(m.rows().count(), 3);
+        assert_eq!(m.as_view_mut().rows_mut().count(), 3);
+        assert!(m.as_view_mut().rows_mut().all(|row| row.is_empty()));
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The `inputs` directory of the `day_XX` crate.
+pub fn inputs_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(format!("day_{:02}", day))
+        .join("inputs")
+}
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+    /// `inputs/example_N.txt`, also found as `exampleN.txt`, or as
+    /// `example.txt` for the first example.
+    Example(u32),
+    /// `inputs/input.txt`
+    Puzzle,
+}
+
+impl InputSource {
+    /// Input given on the command line: a path, or `-` for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// The file to read for `day`, or `None` for stdin.
+    pub fn resolve(&self, day: u8) -> Result<Option<PathBuf>, InputError> {
+        self.resolve_in(&inputs_dir(day))
+    }
+
+    /// `resolve` with the inputs in `dir`.
+    fn resolve_in(&self, dir: &Path) -> Result<Option<PathBuf>, InputError> {
+        let path = match self {
+            InputSource::Stdin => return Ok(None),
+            InputSource::Path(path) => path.clone(),
+            InputSource::Puzzle => dir.join("input.txt"),
+            InputSource::Example(n) => {
+                let mut candidates =
+                    vec![format!("example_{}.txt", n), format!("example{}.txt", n)];
+                if *n == 1 {
+                    candidates.push("example.txt".to_owned());
+                }
+                match candidates.iter().map(|c| dir.join(c)).find(|p| p.is_file()) {
+                    Some(path) => path,
+                    None => {
+                        return Err(InputError::NoExample {
+                            number: *n,
+                            available: available_inputs(dir),
+                        })
+                    }
+                }
+            }
+        };
+        if !path.is_file() {
+            return Err(InputError::NotFound {
+                path,
+                available: available_inputs(dir),
+            });
+        }
+        Ok(Some(path))
+    }
+}
+
+/// Puzzle input read from an `InputSource`.
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    /// File name of the input, or `<stdin>`, to report errors.
+    pub name: String,
+    pub text: String,
+}
+
+impl PuzzleInput {
+    pub fn load(day: u8, source: &InputSource) -> Result<Self, InputError> {
+        let mut text = String::new();
+        match source.resolve(day)? {
+            None => {
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|error| InputError::Io { path: None, error })?;
+                Ok(PuzzleInput {
+                    name: "<stdin>".to_owned(),
+                    text,
+                })
+            }
+            Some(path) => {
+                text = std::fs::read_to_string(&path).map_err(|error| InputError::Io {
+                    path: Some(path.clone()),
+                    error,
+                })?;
+                let name = path.file_name().unwrap_or_default();
+                Ok(PuzzleInput {
+                    name: name.to_string_lossy().into_owned(),
+                    text,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// `available` lists the files of the day's `inputs` directory.
+    NotFound {
+        path: PathBuf,
+        available: Vec<String>,
+    },
+    NoExample {
+        number: u32,
+        available: Vec<String>,
+    },
+    Io {
+        path: Option<PathBuf>,
+        error: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let available = match self {
+            InputError::NotFound { path, available } => {
+                write!(f, "{} doesn't exist", path.display())?;
+                available
+            }
+            InputError::NoExample { number, available } => {
+                write!(f, "no example {}", number)?;
+                available
+            }
+            InputError::Io { path: None, error } => {
+                return write!(f, "can't read stdin: {}", error)
+            }
+            InputError::Io {
+                path: Some(path),
+                error,
+            } => return write!(f, "can't read {}: {}", path.display(), error),
+        };
+        if available.is_empty() {
+            f.write_str(", the day has no inputs")
+        } else {
+            write!(f, ", available inputs: {}", available.join(", "))
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+fn available_inputs(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            entry.file_type().ok()?.is_file().then_some(())?;
+            let name = entry.file_name().into_string().ok()?;
+            (name != "answers.toml").then_some(name)
+        })
+        .collect();
+    files.sort_unstable();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(dir: &Path, source: InputSource) -> Result<String, String> {
+        match source.resolve_in(dir) {
+            Ok(path) => Ok(path.unwrap().file_name().unwrap().to_string_lossy().into()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    #[test]
+    fn resolves_inputs() {
+        let dir = std::env::temp_dir().join(format!("solution-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(
+            resolved(&dir, InputSource::Puzzle),
+            Err(format!(
+                "{} doesn't exist, the day has no inputs",
+                dir.join("input.txt").display()
+            ))
+        );
+
+        for file in ["example.txt", "example2.txt", "answers.toml"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+        assert_eq!(
+            resolved(&dir, InputSource::Example(1)),
+            Ok("example.txt".into())
+        );
+        assert_eq!(
+            resolved(&dir, InputSource::Example(2)),
+            Ok("example2.txt".into())
+        );
+        std::fs::write(dir.join("example_2.txt"), "").unwrap();
+        assert_eq!(
+            resolved(&dir, InputSource::Example(2)),
+            Ok("example_2.txt".into())
+        );
+        assert_eq!(
+            resolved(&dir, InputSource::Example(3)),
+            Err("no example 3, available inputs: example.txt, example2.txt, example_2.txt".into())
+        );
+        assert!(InputSource::Stdin.resolve_in(&dir).unwrap().is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::{self, Display};
+use std::time::Instant;
+
+mod input;
+mod output;
+mod parse;
+
+pub use input::{inputs_dir, InputError, InputSource, PuzzleInput};
+pub use output::{OutputFormat, Record};
+pub use parse::{lines, Line, Lines, ParseError};
+
+pub type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+/// A puzzle answer, as it would be submitted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has no solution (yet).
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+            Answer::Unsolved => f.write_str("unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Number(i64::try_from(n).expect("answer doesn't fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+fn parse_args(
+    mut args: impl Iterator<Item = String>,
+) -> Result<(InputSource, OutputFormat), String> {
+    let mut source = None;
+    let mut format = OutputFormat::Human;
+    while let Some(arg) = args.next() {
+        let next_source = match arg.as_str() {
+            "--json" => {
+                format = OutputFormat::Json;
+                continue;
+            }
+            "--example" => {
+                let n = args.next().ok_or("missing value for --example")?;
+                InputSource::Example(n.parse().map_err(|_| format!("invalid example {:?}", n))?)
+            }
+            arg if arg.starts_with("--") => return Err(format!("unknown argument {:?}", arg)),
+            path => InputSource::from_arg(path),
+        };
+        if source.replace(next_source).is_some() {
+            return Err("only one input can be given".to_owned());
+        }
+    }
+    Ok((source.unwrap_or(InputSource::Stdin), format))
+}
+
+/// Entry point of the `day_XX` binaries: solves both parts of the puzzle
+/// read from the file given as argument, `--example N`, or stdin.
+pub fn main<S: Solution>() {
+    let name = format!("day_{:02}", S::DAY);
+    let (source, format) = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "error: {}\n\nusage: {} [<PATH> | - | --example <N>] [--json]",
+                e, name
+            );
+            std::process::exit(2);
+        }
+    };
+    let input = match PuzzleInput::load(S::DAY, &source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}: {}", name, e);
+            std::process::exit(1);
+        }
+    };
+    let parsed = match S::parse(&input.text) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{} {}:{}", name, input.name, e);
+            std::process::exit(1);
+        }
+    };
+
+    for part in [1, 2] {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        };
+        format.print(&Record {
+            day: S::DAY,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::{self, Display, Write};
+use std::time::Duration;
+
+use crate::Answer;
+
+/// Answer of one part of a day, as printed by the runners.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `day_14 part 2: 3353146900153 (206.18µs)`, with multi-line answers
+    /// starting on the next line.
+    Human,
+    /// One JSON object per line:
+    /// `{"day":14,"part":2,"answer":"3353146900153","elapsed_ns":206180}`.
+    /// Unsolved parts have a `null` answer.
+    Json,
+}
+
+impl OutputFormat {
+    pub fn print(&self, record: &Record) {
+        match self {
+            OutputFormat::Human => println!("{}", record),
+            OutputFormat::Json => println!("{}", record.to_json()),
+        }
+    }
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Answer::Unsolved => "null".to_owned(),
+            answer => json_string(&answer.to_string()),
+        };
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"elapsed_ns":{}}}"#,
+            self.day,
+            self.part,
+            answer,
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day_{:02} part {}", self.day, self.part)?;
+        match &self.answer {
+            Answer::Unsolved => f.write_str(": unsolved"),
+            Answer::Text(text) if text.contains('\n') => write!(
+                f,
+                " ({:.2?}):\n{}",
+                self.elapsed,
+                text.trim_end_matches('\n')
+            ),
+            answer => write!(f, ": {} ({:.2?})", answer, self.elapsed),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_escaping() {
+        let cases = [
+            ("say \"hi\"", r#""say \"hi\"""#),
+            (r"a\b", r#""a\\b""#),
+            ("*  *\n ** \r\t", r#""*  *\n ** \r\t""#),
+            ("\u{0}\u{1f}\u{7f}", r#""\u0000\u001f\u007f""#),
+            ("été ✓ 🎄", "\"été ✓ 🎄\""),
+        ];
+        for (s, json) in cases {
+            assert_eq!(json_string(s), json);
+            assert_eq!(serde_json::from_str::<String>(json).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn record_json() {
+        let mut record = Record {
+            day: 14,
+            part: 2,
+            answer: Answer::Unsolved,
+            elapsed: Duration::from_nanos(206180),
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":14,"part":2,"answer":null,"elapsed_ns":206180}"#
+        );
+        record.answer = 42.into();
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":14,"part":2,"answer":"42","elapsed_ns":206180}"#
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use matrix::GridError;
+
+/// Error produced when the puzzle input doesn't have the expected shape.
+///
+/// `line` and `column` are 1-based, `token` is the piece of input that
+/// couldn't be parsed. It is empty when the input ended early.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        if !self.token.is_empty() {
+            write!(f, ", found {:?}", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<E: Display> From<GridError<E>> for ParseError {
+    fn from(e: GridError<E>) -> Self {
+        ParseError {
+            line: e.row + 1,
+            column: e.col + 1,
+            token: e.found().map(String::from).unwrap_or_default(),
+            expected: e.expected(),
+        }
+    }
+}
+
+/// A line of the input, with its 1-based line number.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Builds an error pointing at `token`, which must be a slice of this
+    /// line. Pass an empty slice at the end of the line for missing tokens.
+    pub fn error(&self, token: &str, expected: impl Display) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= self.text.len())
+            .expect("token is not part of the line");
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            token: token.to_owned(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Error for a token missing at the end of `part`, a slice of this line.
+    pub fn missing(&self, part: &str, expected: impl Display) -> ParseError {
+        self.error(&part[part.len()..], expected)
+    }
+
+    /// Parses `token`, a slice of this line.
+    pub fn parse<T: FromStr>(
+        &self,
+        token: &'a str,
+        expected: impl Display,
+    ) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// `str::strip_prefix` on `part`, a slice of this line, failing when
+    /// `part` doesn't start with `prefix`.
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix).ok_or_else(|| {
+            let first = part.chars().next().map_or(0, char::len_utf8);
+            self.error(&part[..first], format_args!("'{}'", prefix))
+        })
+    }
+
+    /// `str::strip_suffix` on `part`, a slice of this line, failing when
+    /// `part` doesn't end with `suffix`.
+    pub fn strip_suffix(&self, part: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        part.strip_suffix(suffix)
+            .ok_or_else(|| self.missing(part, format_args!("'{}'", suffix)))
+    }
+
+    /// `str::split_once` on `part`, a slice of this line, failing when
+    /// `part` doesn't contain the separator.
+    pub fn split_once(&self, part: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(sep)
+            .ok_or_else(|| self.error(part, format_args!("'{}'", sep)))
+    }
+}
+
+/// Iterator over the numbered lines of the input.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: &'a str,
+    number: usize,
+}
+
+impl<'a> Lines<'a> {
+    /// Returns the next line, or an end of input error.
+    pub fn expect(&mut self, expected: impl Display) -> Result<Line<'a>, ParseError> {
+        self.next().ok_or_else(|| ParseError {
+            line: self.number + 1,
+            column: 1,
+            token: String::new(),
+            expected: expected.to_string(),
+        })
+    }
+
+    /// The input after the lines returned so far.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Error for a grid parsed from `rest`, pointing at its line in the
+    /// input.
+    pub fn grid_error<E: Display>(&self, e: GridError<E>) -> ParseError {
+        let mut error = ParseError::from(e);
+        error.line += self.number;
+        error
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (text, rest) = self.rest.split_once('\n').unwrap_or((self.rest, ""));
+        self.rest = rest;
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        self.number += 1;
+        Some(Line {
+            number: self.number,
+            text,
+        })
+    }
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        rest: input,
+        number: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_after_header() {
+        let input = "header\r\n\nab\nac\n";
+        let mut lines = lines(input);
+        assert_eq!(lines.next().unwrap().text, "header");
+        assert_eq!(lines.next().unwrap().text, "");
+        assert_eq!(lines.rest(), "ab\nac\n");
+
+        let e = matrix::Matrix::from_str_with(lines.rest(), |c| match c {
+            'a' | 'b' => Ok(c),
+            _ => Err("'a' or 'b'"),
+        })
+        .unwrap_err();
+        let e = lines.grid_error(e);
+        assert_eq!(e.to_string(), "4:2: expected 'a' or 'b', found \"c\"");
+        assert_eq!(lines.map(|l| l.number).collect::<Vec<_>>(), [3, 4]);
+    }
+}